@@ -11,12 +11,14 @@ use icu_provider::{
 };
 
 use crate::{
+    Context, TestAction,
     builtins::intl::{
         Service,
         locale::{default_locale, resolve_locale},
         options::{IntlOptions, LocaleMatcher},
     },
     context::icu::IntlProvider,
+    js_string, run_test_actions_with,
 };
 
 #[derive(Debug)]
@@ -60,7 +62,7 @@ impl Service for TestService {
 #[test]
 fn locale_resolution() {
     let provider = IntlProvider::try_new_buffer(boa_icu_provider::buffer());
-    let mut default = default_locale(provider.locale_canonicalizer().unwrap());
+    let mut default = default_locale(&provider).unwrap();
     default = <IntlProvider as DryDataProvider<<TestService as Service>::LangMarker>>::dry_load(
         &provider,
         DataRequest {
@@ -113,3 +115,50 @@ fn locale_resolution() {
         resolve_locale::<TestService>([locale!("bn-Arab")], &mut options, &provider).unwrap();
     assert_eq!(locale, "bn-u-nu-beng".parse().unwrap());
 }
+
+#[test]
+fn context_locale() {
+    let context = &mut Context::builder().locale(js_string!("fr")).build().unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::assert_eq(
+                "new Intl.PluralRules().resolvedOptions().locale",
+                js_string!("fr"),
+            ),
+            TestAction::inspect_context(|context| {
+                context.set_locale(Some(js_string!("de"))).unwrap();
+                assert_eq!(context.locale(), Some(&js_string!("de")));
+            }),
+            TestAction::assert_eq(
+                "new Intl.PluralRules().resolvedOptions().locale",
+                js_string!("de"),
+            ),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn context_locale_is_canonicalized() {
+    let context = &mut Context::default();
+
+    context.set_locale(Some(js_string!("EN-us"))).unwrap();
+    assert_eq!(context.locale(), Some(&js_string!("en-US")));
+
+    let error = context
+        .set_locale(Some(js_string!("not a tag")))
+        .unwrap_err();
+    assert_eq!(
+        error.as_native().unwrap().kind,
+        crate::JsNativeErrorKind::Range
+    );
+    assert_eq!(context.locale(), Some(&js_string!("en-US")));
+
+    assert!(
+        Context::builder()
+            .locale(js_string!("en_US"))
+            .build()
+            .is_err()
+    );
+}
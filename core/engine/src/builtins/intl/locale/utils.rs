@@ -8,12 +8,12 @@ use crate::{
         },
         options::get_option,
     },
-    context::icu::IntlProvider,
+    context::icu::{IcuError, IntlProvider},
     js_string,
     object::JsObject,
 };
 
-use icu_locale::{LanguageIdentifier, Locale, LocaleCanonicalizer};
+use icu_locale::{LanguageIdentifier, Locale};
 use icu_provider::{
     DataIdentifierBorrowed, DataLocale, DataMarker, DataMarkerAttributes, DataRequest,
     DataRequestMetadata, DryDataProvider,
@@ -27,17 +27,22 @@ use tap::TapOptional;
 /// Returns a String value representing the structurally valid and canonicalized
 /// Unicode BCP 47 locale identifier for the host environment's current locale.
 ///
+/// The locale configured on the context takes precedence over the locale of the system.
+///
 /// More information:
 ///  - [ECMAScript reference][spec]
 ///
 /// [spec]: https://tc39.es/ecma402/#sec-defaultlocale
-pub(crate) fn default_locale(canonicalizer: &LocaleCanonicalizer) -> Locale {
-    sys_locale::get_locale()
-        .and_then(|loc| loc.parse::<Locale>().ok())
+pub(crate) fn default_locale(provider: &IntlProvider) -> Result<Locale, IcuError> {
+    let canonicalizer = provider.locale_canonicalizer()?;
+    Ok(provider
+        .default_locale()
+        .cloned()
+        .or_else(|| sys_locale::get_locale().and_then(|loc| loc.parse::<Locale>().ok()))
         .tap_some_mut(|loc| {
             canonicalizer.canonicalize(loc);
         })
-        .unwrap_or(Locale::UNKNOWN)
+        .unwrap_or(Locale::UNKNOWN))
 }
 
 /// Gets the `Locale` struct from a `JsValue`.
@@ -333,7 +338,7 @@ where
     let mut found_locale = if let Some(loc) = found_locale {
         loc
     } else {
-        let default = default_locale(provider.locale_canonicalizer()?);
+        let default = default_locale(provider)?;
         lookup_matching_locale_by_best_fit::<S>([default], provider).ok_or_else(|| {
             JsNativeError::typ().with_message("could not find i18n data for Intl service")
         })?
@@ -33,6 +33,7 @@ pub mod uri;
 pub mod weak;
 pub mod weak_map;
 pub mod weak_set;
+#[cfg(feature = "webassembly")]
 pub mod webassembly;
 
 // Make builder public for external browser API crates
@@ -367,6 +368,9 @@ pub(crate) fn set_default_global_bindings(context: &mut Context) -> JsResult<()>
         global_binding::<temporal::Temporal>(context)?;
     }
 
+    #[cfg(feature = "webassembly")]
+    global_binding::<webassembly::WebAssembly>(context)?;
+
     Ok(())
 }
 
@@ -1776,7 +1776,7 @@ impl String {
             } else {
                 // 3. Else,
                 //     a. Let requestedLocale be ! DefaultLocale().
-                default_locale(context.intl_provider().locale_canonicalizer()?)
+                default_locale(context.intl_provider())?
             };
 
             // 4. Let noExtensionsLocale be the String value that is requestedLocale with any Unicode locale extension sequences (6.2.1) removed.
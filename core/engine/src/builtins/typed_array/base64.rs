@@ -0,0 +1,778 @@
+//! Boa's implementation of the base64 and hex methods of `Uint8Array`.
+//!
+//! More information:
+//!  - [ECMAScript reference][spec]
+//!  - [MDN documentation][mdn]
+//!
+//! [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array/fromBase64
+
+use std::sync::atomic::Ordering;
+
+use base64::{
+    Engine,
+    engine::{GeneralPurpose, general_purpose},
+};
+
+use super::{TypedArray, TypedArrayKind, Uint8Array};
+use crate::{
+    Context, JsArgs, JsNativeError, JsObject, JsResult, JsString, JsValue,
+    builtins::options::get_options_object, js_string, object::builtins::JsUint8Array,
+};
+
+/// The lowercase hexadecimal digits, indexed by their value.
+const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+/// The alphabet used to encode or decode a base64 string.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Alphabet {
+    /// The standard base64 alphabet, using `+` and `/`.
+    Base64,
+    /// The URL-safe base64 alphabet, using `-` and `_`.
+    Base64Url,
+}
+
+/// How the final chunk of a base64 string is decoded if it has less than 4 characters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum LastChunkHandling {
+    /// Decode the chunk, padded or not, ignoring any non-zero padding bits.
+    Loose,
+    /// Only accept a fully padded chunk, with zeroed padding bits.
+    Strict,
+    /// Stop decoding before the chunk, unless it is fully padded.
+    StopBeforePartial,
+}
+
+/// The result of decoding a base64 or hex string.
+#[derive(Debug)]
+struct DecodeResult {
+    /// The number of code units of the string that were decoded.
+    read: usize,
+    /// The decoded bytes.
+    bytes: Vec<u8>,
+    /// The error that stopped the decoding, if any.
+    error: Option<JsNativeError>,
+}
+
+impl DecodeResult {
+    fn ok(read: usize, bytes: Vec<u8>) -> Self {
+        Self {
+            read,
+            bytes,
+            error: None,
+        }
+    }
+
+    fn error(read: usize, bytes: Vec<u8>, message: &'static str) -> Self {
+        Self {
+            read,
+            bytes,
+            error: Some(JsNativeError::syntax().with_message(message)),
+        }
+    }
+}
+
+impl Uint8Array {
+    /// [`Uint8Array.fromBase64 ( string [ , options ] )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.frombase64
+    pub(crate) fn from_base64(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = require_string(args.get_or_undefined(0))?;
+
+        // 2. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_alphabet(&options, context)?;
+
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling = get_last_chunk_handling(&options, context)?;
+
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        let result = from_base64(&string.to_vec(), alphabet, last_chunk_handling, usize::MAX);
+
+        // 10. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(error.into());
+        }
+
+        // 11. Let resultLength be the length of result.[[Bytes]].
+        // 12. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 13. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 14. Return ta.
+        JsUint8Array::from_iter(result.bytes, context).map(Into::into)
+    }
+
+    /// [`Uint8Array.fromHex ( string )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.fromhex
+    pub(crate) fn from_hex(
+        _: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. If string is not a String, throw a TypeError exception.
+        let string = require_string(args.get_or_undefined(0))?;
+
+        // 2. Let result be FromHex(string).
+        let result = from_hex(&string.to_vec(), usize::MAX);
+
+        // 3. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(error.into());
+        }
+
+        // 4. Let resultLength be the length of result.[[Bytes]].
+        // 5. Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%, %Uint8Array.prototype%, resultLength).
+        // 6. Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]] to the value at the corresponding index of result.[[Bytes]].
+        // 7. Return ta.
+        JsUint8Array::from_iter(result.bytes, context).map(Into::into)
+    }
+
+    /// [`Uint8Array.prototype.setFromBase64 ( string [ , options ] )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfrombase64
+    pub(crate) fn set_from_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        validate_uint8_array(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = require_string(args.get_or_undefined(0))?;
+
+        // 4. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(1))?;
+
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_alphabet(&options, context)?;
+
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or "stop-before-partial", throw a TypeError exception.
+        let last_chunk_handling = get_last_chunk_handling(&options, context)?;
+
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        let (into, buf_len) = TypedArray::validate(this, Ordering::SeqCst)?;
+
+        // 13. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = usize::try_from(into.borrow().data().array_length(buf_len))
+            .expect("the length of a typed array must fit in a usize");
+
+        // 14. Let result be FromBase64(string, alphabet, lastChunkHandling, byteLength).
+        let result = from_base64(&string.to_vec(), alphabet, last_chunk_handling, byte_length);
+
+        // 15. Let bytes be result.[[Bytes]].
+        // 16. Let written be the length of bytes.
+        // 17. NOTE: FromBase64 does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 18. Assert: written ≤ byteLength.
+        // 19. Perform SetUint8ArrayBytes(into, bytes).
+        let written = result.bytes.len();
+        set_uint8_array_bytes(&into.upcast(), result.bytes, context);
+
+        // 20. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(error.into());
+        }
+
+        // 21. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 22. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 23. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 24. Return resultObject.
+        Ok(read_written_object(result.read, written, context).into())
+    }
+
+    /// [`Uint8Array.prototype.setFromHex ( string )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.setfromhex
+    pub(crate) fn set_from_hex(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        validate_uint8_array(this)?;
+
+        // 3. If string is not a String, throw a TypeError exception.
+        let string = require_string(args.get_or_undefined(0))?;
+
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+        let (into, buf_len) = TypedArray::validate(this, Ordering::SeqCst)?;
+
+        // 6. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = usize::try_from(into.borrow().data().array_length(buf_len))
+            .expect("the length of a typed array must fit in a usize");
+
+        // 7. Let result be FromHex(string, byteLength).
+        let result = from_hex(&string.to_vec(), byte_length);
+
+        // 8. Let bytes be result.[[Bytes]].
+        // 9. Let written be the length of bytes.
+        // 10. NOTE: FromHex does not invoke any user code, so the ArrayBuffer backing into cannot have been detached or shrunk.
+        // 11. Assert: written ≤ byteLength.
+        // 12. Perform SetUint8ArrayBytes(into, bytes).
+        let written = result.bytes.len();
+        set_uint8_array_bytes(&into.upcast(), result.bytes, context);
+
+        // 13. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(error.into());
+        }
+
+        // 14. Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+        // 15. Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+        // 16. Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+        // 17. Return resultObject.
+        Ok(read_written_object(result.read, written, context).into())
+    }
+
+    /// [`Uint8Array.prototype.toBase64 ( [ options ] )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tobase64
+    pub(crate) fn to_base64(
+        this: &JsValue,
+        args: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        validate_uint8_array(this)?;
+
+        // 3. Let opts be ? GetOptionsObject(options).
+        let options = get_options_object(args.get_or_undefined(0))?;
+
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a TypeError exception.
+        let alphabet = get_alphabet(&options, context)?;
+
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding = options
+            .get(js_string!("omitPadding"), context)?
+            .to_boolean();
+
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = get_uint8_array_bytes(this, context)?;
+
+        // 9. If alphabet is "base64", then
+        //     a. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64 encoding specified in section 4 of RFC 4648. Padding is included if and only if omitPadding is false.
+        // 10. Else,
+        //     a. Assert: alphabet is "base64url".
+        //     b. Let outAscii be the sequence of code points which results from encoding toEncode according to the base64url encoding specified in section 5 of RFC 4648. Padding is included if and only if omitPadding is false.
+        let engine: &GeneralPurpose = match (alphabet, omit_padding) {
+            (Alphabet::Base64, false) => &general_purpose::STANDARD,
+            (Alphabet::Base64, true) => &general_purpose::STANDARD_NO_PAD,
+            (Alphabet::Base64Url, false) => &general_purpose::URL_SAFE,
+            (Alphabet::Base64Url, true) => &general_purpose::URL_SAFE_NO_PAD,
+        };
+
+        // 11. Return CodePointsToString(outAscii).
+        Ok(JsString::from(engine.encode(to_encode)).into())
+    }
+
+    /// [`Uint8Array.prototype.toHex ( )`][spec]
+    ///
+    /// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-uint8array.prototype.tohex
+    pub(crate) fn to_hex(
+        this: &JsValue,
+        _: &[JsValue],
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        validate_uint8_array(this)?;
+
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        let to_encode = get_uint8_array_bytes(this, context)?;
+
+        // 4. Let out be the empty String.
+        let mut out = String::with_capacity(to_encode.len() * 2);
+
+        // 5. For each byte byte of toEncode, do
+        for byte in to_encode {
+            // a. Let hex be Number::toString(𝔽(byte), 16).
+            // b. Set hex to StringPad(hex, 2, "0", start).
+            // c. Set out to the string-concatenation of out and hex.
+            out.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+            out.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+        }
+
+        // 6. Return out.
+        Ok(JsString::from(out).into())
+    }
+}
+
+/// Returns the argument if it is a string, or throws a `TypeError`.
+fn require_string(value: &JsValue) -> JsResult<JsString> {
+    value.as_string().ok_or_else(|| {
+        JsNativeError::typ()
+            .with_message("argument must be a string")
+            .into()
+    })
+}
+
+/// Abstract operation [`ValidateUint8Array ( ta )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-validateuint8array
+fn validate_uint8_array(ta: &JsValue) -> JsResult<()> {
+    // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+    // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError exception.
+    let is_uint8_array = ta
+        .as_object()
+        .and_then(|o| o.clone().downcast::<TypedArray>().ok())
+        .is_some_and(|ta| ta.borrow().data().kind() == TypedArrayKind::Uint8);
+
+    if !is_uint8_array {
+        return Err(JsNativeError::typ()
+            .with_message("`this` is not a Uint8Array object")
+            .into());
+    }
+
+    // 3. Return unused.
+    Ok(())
+}
+
+/// Abstract operation [`GetUint8ArrayBytes ( ta )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-getuint8arraybytes
+fn get_uint8_array_bytes(ta: &JsValue, context: &mut Context) -> JsResult<Vec<u8>> {
+    // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+    // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError exception.
+    let (ta, buf_len) = TypedArray::validate(ta, Ordering::SeqCst)?;
+
+    // 4. Let len be TypedArrayLength(taRecord).
+    let len = ta.borrow().data().array_length(buf_len);
+    let ta = ta.upcast();
+
+    // 5. Let byteOffset be ta.[[ByteOffset]].
+    // 6. Let bytes be a new empty List.
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    //     a. Let byteIndex be byteOffset + index.
+    //     b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+    //     c. Append byte to bytes.
+    //     d. Set index to index + 1.
+    // 9. Return bytes.
+    (0..len)
+        .map(|index| {
+            ta.get(index, context)
+                .expect("Get cannot fail here")
+                .to_uint8(context)
+        })
+        .collect()
+}
+
+/// Abstract operation [`SetUint8ArrayBytes ( into, bytes )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-setuint8arraybytes
+fn set_uint8_array_bytes(into: &JsObject, bytes: Vec<u8>, context: &mut Context) {
+    // 1. Let offset be into.[[ByteOffset]].
+    // 2. Let len be the length of bytes.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < len,
+    for (index, byte) in bytes.into_iter().enumerate() {
+        // a. Let byte be bytes[index].
+        // b. Let byteIndexInBuffer be index + offset.
+        // c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+        // d. Set index to index + 1.
+        into.set(index, byte, true, context)
+            .expect("Set cannot fail here");
+    }
+}
+
+/// Create the `{ read, written }` object returned by the `setFrom*` methods.
+fn read_written_object(read: usize, written: usize, context: &mut Context) -> JsObject {
+    let object = JsObject::with_object_proto(context.intrinsics());
+    object
+        .create_data_property_or_throw(js_string!("read"), read, context)
+        .expect("cannot fail for a new object");
+    object
+        .create_data_property_or_throw(js_string!("written"), written, context)
+        .expect("cannot fail for a new object");
+    object
+}
+
+/// Get the `alphabet` option of the base64 methods.
+fn get_alphabet(options: &JsObject, context: &mut Context) -> JsResult<Alphabet> {
+    let alphabet = options.get(js_string!("alphabet"), context)?;
+    if alphabet.is_undefined() {
+        return Ok(Alphabet::Base64);
+    }
+
+    match alphabet
+        .as_string()
+        .map(|s| s.to_std_string_escaped())
+        .as_deref()
+    {
+        Some("base64") => Ok(Alphabet::Base64),
+        Some("base64url") => Ok(Alphabet::Base64Url),
+        _ => Err(JsNativeError::typ()
+            .with_message("alphabet must be \"base64\" or \"base64url\"")
+            .into()),
+    }
+}
+
+/// Get the `lastChunkHandling` option of the base64 methods.
+fn get_last_chunk_handling(
+    options: &JsObject,
+    context: &mut Context,
+) -> JsResult<LastChunkHandling> {
+    let handling = options.get(js_string!("lastChunkHandling"), context)?;
+    if handling.is_undefined() {
+        return Ok(LastChunkHandling::Loose);
+    }
+
+    match handling
+        .as_string()
+        .map(|s| s.to_std_string_escaped())
+        .as_deref()
+    {
+        Some("loose") => Ok(LastChunkHandling::Loose),
+        Some("strict") => Ok(LastChunkHandling::Strict),
+        Some("stop-before-partial") => Ok(LastChunkHandling::StopBeforePartial),
+        _ => Err(JsNativeError::typ()
+            .with_message(
+                "lastChunkHandling must be \"loose\", \"strict\" or \"stop-before-partial\"",
+            )
+            .into()),
+    }
+}
+
+/// Abstract operation [`SkipAsciiWhitespace ( string, index )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-skipasciiwhitespace
+fn skip_ascii_whitespace(string: &[u16], index: usize) -> usize {
+    string[index..]
+        .iter()
+        .position(|&c| !matches!(c, 0x09 | 0x0A | 0x0C | 0x0D | 0x20))
+        .map_or(string.len(), |offset| index + offset)
+}
+
+/// Returns the value of a character of the standard base64 alphabet.
+fn base64_value(c: u16) -> Option<u8> {
+    let c = u8::try_from(c).ok()?;
+    match c {
+        b'A'..=b'Z' => Some(c - b'A'),
+        b'a'..=b'z' => Some(c - b'a' + 26),
+        b'0'..=b'9' => Some(c - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+/// Returns the value of a hexadecimal digit.
+fn hex_value(c: u16) -> Option<u8> {
+    let c = u8::try_from(c).ok()?;
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
+
+/// Abstract operation [`DecodeBase64Chunk ( chunk [ , throwOnExtraBits ] )`][spec]
+///
+/// Decodes a chunk of 2 to 4 base64 values, returning the decoded bytes, or `None` if
+/// `throw_on_extra_bits` is `true` and the padding bits of a partial chunk aren't zero.
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-decodebase64chunk
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool) -> Option<Vec<u8>> {
+    // 1. Let chunkLength be the length of chunk.
+    // 2. If chunkLength = 2, then
+    //     a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength = 3, then
+    //     a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //     a. Assert: chunkLength is 4.
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from decoding chunk as base64.
+    let sextets = chunk
+        .iter()
+        .chain(std::iter::repeat(&0))
+        .take(4)
+        .fold(0u32, |acc, &value| (acc << 6) | u32::from(value));
+    let [_, first, second, third] = sextets.to_be_bytes();
+
+    match chunk.len() {
+        // 6. Let bytes be the List whose elements are the elements of byteSequence, in order.
+        // 7. If chunkLength = 2, then
+        2 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and bytes[1] ≠ 0, then
+            //     i. Throw a SyntaxError exception.
+            // c. Return « bytes[0] ».
+            (!throw_on_extra_bits || second == 0).then(|| vec![first])
+        }
+        // 8. Else if chunkLength = 3, then
+        3 => {
+            // a. Assert: throwOnExtraBits is present.
+            // b. If throwOnExtraBits is true and bytes[2] ≠ 0, then
+            //     i. Throw a SyntaxError exception.
+            // c. Return « bytes[0], bytes[1] ».
+            (!throw_on_extra_bits || third == 0).then(|| vec![first, second])
+        }
+        // 9. Else,
+        //     a. Return bytes.
+        _ => Some(vec![first, second, third]),
+    }
+}
+
+/// Abstract operation [`FromBase64 ( string, alphabet, lastChunkHandling [ , maxLength ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-frombase64
+fn from_base64(
+    string: &[u16],
+    alphabet: Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> DecodeResult {
+    const INVALID: &str = "invalid base64 string";
+
+    // 1. If maxLength is not present, then
+    //     a. Let maxLength be 2^53 - 1.
+    // 2. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return DecodeResult::ok(0, Vec::new());
+    }
+
+    // 3. Let read be 0.
+    let mut read = 0;
+    // 4. Let bytes be « ».
+    let mut bytes = Vec::new();
+    // 5. Let chunk be the empty String.
+    // 6. Let chunkLength be 0.
+    let mut chunk = Vec::with_capacity(4);
+    // 7. Let index be 0.
+    let mut index = 0;
+    // 8. Let length be the length of string.
+    let length = string.len();
+
+    // 9. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if !chunk.is_empty() {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    LastChunkHandling::StopBeforePartial => {
+                        // a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult::ok(read, bytes);
+                    }
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk.len() == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            return DecodeResult::error(read, bytes, INVALID);
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk, false).
+                        bytes.extend(
+                            decode_base64_chunk(&chunk, false).expect("extra bits are allowed"),
+                        );
+                    }
+                    // 3. Else,
+                    LastChunkHandling::Strict => {
+                        // a. Assert: lastChunkHandling is "strict".
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                        return DecodeResult::error(read, bytes, INVALID);
+                    }
+                }
+            }
+
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string[index];
+
+        // d. Set index to index + 1.
+        index += 1;
+
+        // e. If char is "=", then
+        if char == u16::from(b'=') {
+            // i. If chunkLength < 2, then
+            if chunk.len() < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, INVALID);
+            }
+
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+
+            // iii. If chunkLength = 2, then
+            if chunk.len() == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult::ok(read, bytes);
+                    }
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, INVALID);
+                }
+
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if string[index] == u16::from(b'=') {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, INVALID);
+            }
+
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk, throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            let Some(decoded) = decode_base64_chunk(&chunk, throw_on_extra_bits) else {
+                // 1. Let error be decodeResult.[[Value]].
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, "invalid base64 padding bits");
+            };
+
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            bytes.extend(decoded);
+
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+
+        // f. If alphabet is "base64url", then
+        if alphabet == Alphabet::Base64Url {
+            char = match u8::try_from(char) {
+                // i. If char is either "+" or "/", then
+                Ok(b'+' | b'/') => {
+                    // 1. Let error be a new SyntaxError exception.
+                    // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, INVALID);
+                }
+                // ii. Else if char is "-", then
+                //     1. Set char to "+".
+                Ok(b'-') => u16::from(b'+'),
+                // iii. Else if char is "_", then
+                //     1. Set char to "/".
+                Ok(b'_') => u16::from(b'/'),
+                _ => char,
+            };
+        }
+
+        // g. If the sole code unit of char is not an element of the standard base64 alphabet, then
+        let Some(value) = base64_value(char) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, INVALID);
+        };
+
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - bytes.len();
+
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and chunkLength = 3, then
+        if (remaining == 1 && chunk.len() == 2) || (remaining == 2 && chunk.len() == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(read, bytes);
+        }
+
+        // j. Set chunk to the string-concatenation of chunk and char.
+        // k. Set chunkLength to the length of chunk.
+        chunk.push(value);
+
+        // l. If chunkLength = 4, then
+        if chunk.len() == 4 {
+            // i. Set bytes to the list-concatenation of bytes and ! DecodeBase64Chunk(chunk).
+            bytes.extend(decode_base64_chunk(&chunk, false).expect("full chunks cannot fail"));
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk.clear();
+            // iv. Set read to index.
+            read = index;
+            // v. If the length of bytes = maxLength, then
+            if bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return DecodeResult::ok(read, bytes);
+            }
+        }
+    }
+}
+
+/// Abstract operation [`FromHex ( string [ , maxLength ] )`][spec]
+///
+/// [spec]: https://tc39.es/proposal-arraybuffer-base64/spec/#sec-fromhex
+fn from_hex(string: &[u16], max_length: usize) -> DecodeResult {
+    // 1. If maxLength is not present, let maxLength be 2^53 - 1.
+    // 2. Let length be the length of string.
+    let length = string.len();
+    // 3. Let bytes be « ».
+    let mut bytes = Vec::with_capacity(length.min(max_length.saturating_mul(2)) / 2);
+    // 4. Let read be 0.
+    let mut read = 0;
+
+    // 5. If length modulo 2 is not 0, then
+    if length % 2 != 0 {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        return DecodeResult::error(read, bytes, "hex string must have an even length");
+    }
+
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while read < length && bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in "0123456789abcdefABCDEF", then
+        let (Some(high), Some(low)) = (hex_value(string[read]), hex_value(string[read + 1])) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, "invalid hex string");
+        };
+
+        // c. Set read to read + 2.
+        read += 2;
+
+        // d. Let byte be the integer value represented by hexits in base-16 notation, using the letters A-F and a-f for digits with values 10 through 15.
+        // e. Append byte to bytes.
+        bytes.push((high << 4) | low);
+    }
+
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    DecodeResult::ok(read, bytes)
+}
@@ -27,10 +27,14 @@ use crate::{
 };
 use boa_gc::{Finalize, Trace};
 
+mod base64;
 mod builtin;
 mod element;
 mod object;
 
+#[cfg(test)]
+mod tests;
+
 pub(crate) use builtin::{BuiltinTypedArray, is_valid_integer_index};
 #[cfg(feature = "float16")]
 pub(crate) use element::Float16;
@@ -52,7 +56,7 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
             .name(js_string!("get [Symbol.species]"))
             .build();
 
-        BuiltInBuilder::from_standard_constructor::<Self>(realm)
+        let mut builder = BuiltInBuilder::from_standard_constructor::<Self>(realm)
             .prototype(
                 realm
                     .intrinsics()
@@ -78,8 +82,20 @@ impl<T: TypedArrayMarker> IntrinsicObject for T {
                 js_string!("BYTES_PER_ELEMENT"),
                 size_of::<T::Element>(),
                 Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::PERMANENT,
-            )
-            .build();
+            );
+
+        // Only `Uint8Array` can be converted from and to base64 and hex strings.
+        if T::ERASED == TypedArrayKind::Uint8 {
+            builder = builder
+                .static_method(Uint8Array::from_base64, js_string!("fromBase64"), 1)
+                .static_method(Uint8Array::from_hex, js_string!("fromHex"), 1)
+                .method(Uint8Array::set_from_base64, js_string!("setFromBase64"), 1)
+                .method(Uint8Array::set_from_hex, js_string!("setFromHex"), 1)
+                .method(Uint8Array::to_base64, js_string!("toBase64"), 0)
+                .method(Uint8Array::to_hex, js_string!("toHex"), 0);
+        }
+
+        builder.build();
     }
 }
 
@@ -0,0 +1,52 @@
+use boa_macros::js_str;
+
+use crate::{JsNativeErrorKind, TestAction, run_test_actions};
+
+#[test]
+fn uint8array_base64_and_hex() {
+    run_test_actions([
+        TestAction::run(
+            r#"
+            function cmp(a, b) {
+                return a.length === b.length && a.every((v, i) => v === b[i]);
+            }
+
+            var bytes = new Uint8Array([0xfb, 0xff, 0x01, 0x7f]);
+            var target = new Uint8Array(4);
+            var result = target.setFromBase64("SGVs bG8=");
+        "#,
+        ),
+        TestAction::assert_eq("bytes.toBase64()", js_str!("+/8Bfw==")),
+        TestAction::assert_eq(
+            "bytes.toBase64({ alphabet: 'base64url', omitPadding: true })",
+            js_str!("-_8Bfw"),
+        ),
+        TestAction::assert_eq("bytes.toHex()", js_str!("fbff017f")),
+        TestAction::assert("cmp(Uint8Array.fromBase64('+/8Bfw=='), bytes)"),
+        TestAction::assert(
+            "cmp(Uint8Array.fromBase64(' -_8B\\nfw ', { alphabet: 'base64url' }), bytes)",
+        ),
+        TestAction::assert("cmp(Uint8Array.fromHex('FBff017F'), bytes)"),
+        TestAction::assert(
+            "cmp(Uint8Array.fromBase64('SGVsbG8', { lastChunkHandling: 'stop-before-partial' }), [72, 101, 108])",
+        ),
+        TestAction::assert(
+            "cmp(target, [72, 101, 108, 0]) && result.read === 4 && result.written === 3",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromBase64('SGVsbG8', { lastChunkHandling: 'strict' })",
+            JsNativeErrorKind::Syntax,
+            "invalid base64 string",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.fromHex('abc')",
+            JsNativeErrorKind::Syntax,
+            "hex string must have an even length",
+        ),
+        TestAction::assert_native_error(
+            "Uint8Array.prototype.toHex.call(new Int8Array(1))",
+            JsNativeErrorKind::Type,
+            "`this` is not a Uint8Array object",
+        ),
+    ]);
+}
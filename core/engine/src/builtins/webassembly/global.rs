@@ -11,10 +11,10 @@ use crate::{
     string::StaticJsStrings,
     value::JsValue,
     Context, JsArgs, JsData, JsNativeError, JsResult, js_string,
-    JsString, realm::Realm, property::Attribute
+    JsString, JsSymbol, realm::Realm, property::Attribute
 };
 use boa_gc::{Finalize, Trace};
-use super::runtime::WebAssemblyRuntime;
+use super::{brand_check_error, runtime::{Resource, SharedGuard, WebAssemblyRuntime}, value};
 
 /// JavaScript `WebAssembly.Global` builtin implementation.
 #[derive(Debug, Copy, Clone)]
@@ -36,6 +36,11 @@ impl IntrinsicObject for WebAssemblyGlobal {
                 Some(value_setter),
                 Attribute::CONFIGURABLE,
             )
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Global"),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -50,7 +55,7 @@ impl BuiltInObject for WebAssemblyGlobal {
 
 impl BuiltInConstructor for WebAssemblyGlobal {
     const LENGTH: usize = 2;
-    const P: usize = 2; // value property, valueOf method
+    const P: usize = 2; // value accessor, @@toStringTag
     const SP: usize = 0; // no static properties
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -132,12 +137,17 @@ impl WebAssemblyGlobal {
         // Get the WebAssembly runtime
         let runtime = WebAssemblyRuntime::get_or_create(context)?;
 
-        // Convert JavaScript value to WebAssembly value
-        let wasm_value = Self::js_value_to_wasm_value(value, &descriptor.value_type, context)?;
+        // Convert JavaScript value to WebAssembly value, or use the default value if missing
+        let value_type = Self::value_type_to_wasmtime(&descriptor.value_type);
+        let wasm_value = if value.is_undefined() {
+            value::default_value(&value_type)?.into()
+        } else {
+            value::to_webassembly_value(value, &value_type, context)?
+        };
 
         // Convert to wasmtime GlobalType
         let global_type = wasmtime::GlobalType::new(
-            Self::value_type_to_wasmtime(&descriptor.value_type),
+            value_type,
             if descriptor.mutable {
                 wasmtime::Mutability::Var
             } else {
@@ -158,10 +168,12 @@ impl WebAssemblyGlobal {
             context,
         )?;
 
+        let mut global_data = WebAssemblyGlobalData::new(global_id.clone(), descriptor);
+        global_data.guard = runtime.guard(Resource::Global(global_id));
         let global_obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             proto,
-            WebAssemblyGlobalData::new(global_id, descriptor),
+            global_data,
         );
 
         Ok(global_obj.into())
@@ -180,139 +192,35 @@ impl WebAssemblyGlobal {
         }
     }
 
-    /// Convert JavaScript value to WebAssembly value
-    fn js_value_to_wasm_value(
-        value: &JsValue,
-        value_type: &ValueType,
-        context: &mut Context,
-    ) -> JsResult<wasmtime::Val> {
-        match value_type {
-            ValueType::I32 => {
-                let num = value.to_i32(context)?;
-                Ok(wasmtime::Val::I32(num))
-            }
-            ValueType::I64 => {
-                // WebAssembly i64 values are represented as BigInt in JavaScript
-                if let Some(bigint) = value.as_bigint() {
-                    // Convert BigInt to i64
-                    let i128_val = bigint.to_i128();
-                    let i64_val = i128_val as i64; // Truncate to i64
-                    Ok(wasmtime::Val::I64(i64_val))
-                } else {
-                    // Fallback to regular number conversion
-                    let num = value.to_number(context)? as i64;
-                    Ok(wasmtime::Val::I64(num))
-                }
-            }
-            ValueType::F32 => {
-                let num = value.to_number(context)? as f32;
-                Ok(wasmtime::Val::F32(num.to_bits()))
-            }
-            ValueType::F64 => {
-                let num = value.to_number(context)?;
-                Ok(wasmtime::Val::F64(num.to_bits()))
-            }
-            ValueType::V128 => {
-                // V128 values are complex and require special handling
-                // For now, default to zeros
-                Ok(wasmtime::Val::V128(0.into()))
-            }
-            ValueType::ExternRef => {
-                // Convert any JavaScript value to externref
-                if value.is_null() {
-                    Ok(wasmtime::Val::ExternRef(None))
-                } else {
-                    // TODO: Implement proper externref value wrapping
-                    Ok(wasmtime::Val::ExternRef(None))
-                }
-            }
-            ValueType::FuncRef => {
-                // Convert JavaScript function to funcref
-                if value.is_null() {
-                    Ok(wasmtime::Val::FuncRef(None))
-                } else {
-                    // TODO: Implement proper function reference wrapping
-                    Ok(wasmtime::Val::FuncRef(None))
-                }
-            }
-        }
-    }
-
-    /// `get/set WebAssembly.Global.prototype.value`
+    /// `get WebAssembly.Global.prototype.value`
     ///
-    /// Returns or sets the value of the global.
+    /// Returns the value of the global.
     fn value(
         this: &JsValue,
-        args: &[JsValue],
+        _: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let global_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Global.value called on non-object")
-        })?;
-
-        let global_data = global_obj.downcast_ref::<WebAssemblyGlobalData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Global.value called on non-Global object")
-        })?;
-
-        // If no arguments, this is a getter
-        if args.is_empty() {
-            // Get the runtime to access the global value
-            let runtime = WebAssemblyRuntime::get_or_create(context)?;
-
-            // TODO: Implement actual global value retrieval from wasmtime
-            // For now, return default values based on type
-            match global_data.descriptor().value_type {
-                ValueType::I32 => Ok(JsValue::new(0)),
-                ValueType::I64 => Ok(JsValue::new(0)), // Should be BigInt
-                ValueType::F32 => Ok(JsValue::new(0.0)),
-                ValueType::F64 => Ok(JsValue::new(0.0)),
-                ValueType::V128 => Ok(JsValue::new(0)), // Should be proper V128 representation
-                ValueType::ExternRef => Ok(JsValue::null()),
-                ValueType::FuncRef => Ok(JsValue::null()),
-            }
-        } else {
-            // This is a setter
-            if !global_data.descriptor().mutable {
-                return Err(JsNativeError::typ()
-                    .with_message("Cannot set value of immutable WebAssembly.Global")
-                    .into());
-            }
-
-            let new_value = args.get_or_undefined(0);
-
-            // Get the runtime to set the global value
-            let runtime = WebAssemblyRuntime::get_or_create(context)?;
-
-            // Convert and validate the new value
-            let wasm_value = Self::js_value_to_wasm_value(
-                new_value,
-                &global_data.descriptor().value_type,
-                context,
-            )?;
-
-            // TODO: Implement actual global value setting in wasmtime
-
-            Ok(JsValue::undefined())
-        }
+        let global_data = Self::this_global_data(this, "value")?;
+        let runtime = WebAssemblyRuntime::get_or_create(context)?;
+        let global = runtime.get_global(global_data.global_id()).ok_or_else(released_error)?;
+
+        runtime
+            .with_extern_store_mut(global_data.global_id(), |store| {
+                let wasm_value = global.get(&mut *store);
+                value::to_js_value(&wasm_value, store, &runtime)
+            })
+            .ok_or_else(released_error)?
     }
 
-    /// Setter for WebAssembly.Global.prototype.value
+    /// `set WebAssembly.Global.prototype.value`
+    ///
+    /// Sets the value of a mutable global.
     fn set_value(
         this: &JsValue,
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let global_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Global.value called on non-object")
-        })?;
-
-        let global_data = global_obj.downcast_ref::<WebAssemblyGlobalData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Global.value called on non-Global object")
-        })?;
+        let global_data = Self::this_global_data(this, "value")?;
 
         if !global_data.descriptor().mutable {
             return Err(JsNativeError::typ()
@@ -320,22 +228,38 @@ impl WebAssemblyGlobal {
                 .into());
         }
 
-        let new_value = args.get_or_undefined(0);
-
-        // Get the runtime to set the global value
-        let runtime = WebAssemblyRuntime::get_or_create(context)?;
-
         // Convert and validate the new value
-        let wasm_value = Self::js_value_to_wasm_value(
-            new_value,
-            &global_data.descriptor().value_type,
-            context,
-        )?;
+        let value_type = Self::value_type_to_wasmtime(&global_data.descriptor().value_type);
+        let wasm_value = value::to_webassembly_value(args.get_or_undefined(0), &value_type, context)?;
 
-        // TODO: Implement actual global value setting in wasmtime
+        let runtime = WebAssemblyRuntime::get_or_create(context)?;
+        let global = runtime.get_global(global_data.global_id()).ok_or_else(released_error)?;
+
+        runtime
+            .with_extern_store_mut(global_data.global_id(), |store| {
+                let wasm_value = wasm_value.into_val(store, &runtime)?;
+                global.set(store, wasm_value)
+            })
+            .ok_or_else(released_error)?
+            .map_err(|err| {
+                JsNativeError::typ()
+                    .with_message(format!("WebAssembly.Global value could not be set: {err}"))
+            })?;
 
         Ok(JsValue::undefined())
     }
+
+    /// Get the internal data of `this`, which must be a `WebAssembly.Global`
+    fn this_global_data(this: &JsValue, member: &str) -> JsResult<WebAssemblyGlobalData> {
+        this.as_object()
+            .and_then(|obj| obj.downcast_ref::<WebAssemblyGlobalData>().map(|data| data.clone()))
+            .ok_or_else(|| brand_check_error("Global", member).into())
+    }
+}
+
+/// The error thrown when the native global backing an object is missing.
+fn released_error() -> JsNativeError {
+    JsNativeError::typ().with_message("WebAssembly.Global has no backing global")
 }
 
 /// Internal data for WebAssembly.Global instances
@@ -343,11 +267,14 @@ impl WebAssemblyGlobal {
 pub struct WebAssemblyGlobalData {
     global_id: String,
     descriptor: GlobalDescriptor,
+    /// Releases the global from the runtime once this object is collected.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
 }
 
 impl WebAssemblyGlobalData {
     pub fn new(global_id: String, descriptor: GlobalDescriptor) -> Self {
-        Self { global_id, descriptor }
+        Self { global_id, descriptor, guard: SharedGuard::default() }
     }
 
     pub fn global_id(&self) -> &str {
@@ -5,16 +5,18 @@
 //! https://webassembly.github.io/spec/js-api/#instances
 
 use crate::{
-    builtins::{BuiltInObject, IntrinsicObject, BuiltInConstructor, BuiltInBuilder},
+    builtins::{Array, BuiltInObject, IntrinsicObject, BuiltInConstructor, BuiltInBuilder},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    object::{internal_methods::get_prototype_from_constructor, JsObject},
+    native_function::NativeFunction,
+    object::{internal_methods::get_prototype_from_constructor, FunctionObjectBuilder, JsObject},
     string::StaticJsStrings,
     value::JsValue,
-    Context, JsArgs, JsData, JsNativeError, JsResult, js_string,
-    JsString, realm::Realm, property::Attribute
+    Context, JsArgs, JsData, JsError, JsNativeError, JsResult, js_string,
+    JsString, JsSymbol, realm::Realm, property::Attribute
 };
 use boa_gc::{Finalize, Trace};
-use super::runtime::WebAssemblyRuntime;
+use super::runtime::{Resource, SharedGuard, WebAssemblyRuntime};
+use super::value;
 
 /// JavaScript `WebAssembly.Instance` builtin implementation.
 #[derive(Debug, Copy, Clone)]
@@ -23,6 +25,11 @@ pub struct WebAssemblyInstance;
 impl IntrinsicObject for WebAssemblyInstance {
     fn init(realm: &Realm) {
         BuiltInBuilder::from_standard_constructor::<Self>(realm)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Instance"),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -37,7 +44,7 @@ impl BuiltInObject for WebAssemblyInstance {
 
 impl BuiltInConstructor for WebAssemblyInstance {
     const LENGTH: usize = 1;
-    const P: usize = 1; // exports property
+    const P: usize = 1; // @@toStringTag
     const SP: usize = 0; // no static properties
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -122,14 +129,20 @@ impl WebAssemblyInstance {
             context,
         )?;
 
+        let mut instance_data = WebAssemblyInstanceData::new(instance_id.clone(), store_id.clone());
+        instance_data.guard = runtime.guard(Resource::Instance {
+            instance_id: instance_id.clone(),
+            store_id: store_id.clone(),
+        });
+        let guard = instance_data.guard.clone();
         let instance_obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             proto,
-            WebAssemblyInstanceData::new(instance_id.clone(), store_id.clone()),
+            instance_data,
         );
 
         // Create and populate the exports object
-        let exports_obj = Self::create_exports_object(&instance_id, &store_id, context)?;
+        let exports_obj = Self::create_exports_object(&instance_id, &store_id, &guard, context)?;
         instance_obj.set(js_string!("exports"), exports_obj, false, context)?;
 
         Ok(instance_obj.into())
@@ -143,15 +156,14 @@ impl WebAssemblyInstance {
     ) -> JsResult<std::collections::HashMap<String, std::collections::HashMap<String, wasmtime::Extern>>> {
         let mut imports = std::collections::HashMap::new();
 
-        // If import_object is undefined or null, use empty imports
-        if import_object.is_undefined() || import_object.is_null() {
-            return Ok(imports);
-        }
+        let import_obj = Self::validate_import_object(import_object)?;
 
-        let import_obj = import_object.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("Import object must be an object")
-        })?;
+        // If the module has imports, an import object is required
+        if module.imports().next().is_some() && import_obj.is_none() {
+            return Err(JsNativeError::typ()
+                .with_message("WebAssembly: an import object is required by modules with imports")
+                .into());
+        }
 
         // For now, we'll support modules without imports
         // TODO: Implement full import processing when other WebAssembly APIs are ready
@@ -165,6 +177,24 @@ impl WebAssemblyInstance {
         Ok(imports)
     }
 
+    /// Validate the `importObject` argument, which is either `undefined` or an object
+    ///
+    /// More information:
+    ///  - [WebAssembly JS API specification][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#read-the-imports
+    pub(crate) fn validate_import_object(import_object: &JsValue) -> JsResult<Option<JsObject>> {
+        if import_object.is_undefined() {
+            return Ok(None);
+        }
+
+        import_object.as_object().map(Some).ok_or_else(|| {
+            JsNativeError::typ()
+                .with_message("WebAssembly: import object must be an object or undefined")
+                .into()
+        })
+    }
+
     /// Convert a JavaScript value to a wasmtime::Extern based on the import type
     fn js_value_to_extern(
         _value: &JsValue,
@@ -179,34 +209,157 @@ impl WebAssemblyInstance {
     }
 
     /// Create the exports object for an instantiated module
+    ///
+    /// More information:
+    ///  - [WebAssembly JS API specification][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#create-an-exports-object
     fn create_exports_object(
         instance_id: &str,
         store_id: &str,
+        guard: &SharedGuard,
         context: &mut Context,
     ) -> JsResult<JsObject> {
         let exports_obj = JsObject::with_object_proto(context.intrinsics());
 
         // Get the runtime to access the instance
         let runtime = WebAssemblyRuntime::get_or_create(context)?;
-
-        // TODO: Get the actual exports from the wasmtime instance
-        // For now, create an empty exports object
-        // This will be populated when we implement proper instance management in the runtime
+        let instance = runtime.get_instance(instance_id).ok_or_else(released_error)?;
+
+        // Collect the exported functions with the number of their parameters
+        let functions = runtime
+            .with_store_mut(store_id, |store| {
+                let functions: Vec<_> = instance
+                    .exports(&mut *store)
+                    .filter_map(|export| {
+                        let name = export.name().to_owned();
+                        export.into_func().map(|func| (name, func))
+                    })
+                    .collect();
+                functions
+                    .into_iter()
+                    .map(|(name, func)| {
+                        let length = func.ty(&*store).params().len();
+                        (name, func, length)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .ok_or_else(released_error)?;
+
+        // TODO: Export memories, tables and globals once their objects can wrap the
+        // externs of an instance store.
+        for (name, func, length) in functions {
+            let name = JsString::from(name.as_str());
+            let function = ExportedFunction {
+                func,
+                store_id: store_id.to_owned(),
+                guard: guard.clone(),
+            };
+            let function = FunctionObjectBuilder::new(
+                context.realm(),
+                NativeFunction::from_copy_closure_with_captures(
+                    |_, args, function, context| function.call(args, context),
+                    function,
+                ),
+            )
+            .name(name.clone())
+            .length(length)
+            .build();
+            exports_obj.create_data_property_or_throw(name, function, context)?;
+        }
 
         Ok(exports_obj)
     }
 }
 
+/// An exported WebAssembly function, captured by the JavaScript function wrapping it
+#[derive(Trace, Finalize)]
+struct ExportedFunction {
+    #[unsafe_ignore_trace]
+    func: wasmtime::Func,
+    store_id: String,
+    /// Keeps the instance, and the store the function lives in, alive as long as the
+    /// JavaScript function.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
+}
+
+impl ExportedFunction {
+    /// Call the function with JavaScript arguments
+    ///
+    /// A single result is returned as is, while multiple results are returned as an array.
+    ///
+    /// More information:
+    ///  - [WebAssembly JS API specification][spec]
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#call-an-exported-function
+    fn call(&self, args: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+        let runtime = WebAssemblyRuntime::get_or_create(context)?;
+        let func_ty = runtime
+            .with_store_mut(&self.store_id, |store| self.func.ty(&*store))
+            .ok_or_else(released_error)?;
+
+        // Convert the arguments before locking the store, since conversions can run
+        // JavaScript code.
+        let params = func_ty
+            .params()
+            .enumerate()
+            .map(|(index, ty)| value::to_webassembly_value(args.get_or_undefined(index), &ty, context))
+            .collect::<JsResult<Vec<_>>>()?;
+
+        let mut results = runtime
+            .with_store_mut(&self.store_id, |store| {
+                let params = params
+                    .into_iter()
+                    .map(|param| param.into_val(store, &runtime))
+                    .collect::<wasmtime::Result<Vec<_>>>()
+                    .map_err(call_error)?;
+                let mut results = vec![wasmtime::Val::I32(0); func_ty.results().len()];
+                // The engine enables async support, which rules out synchronous calls.
+                futures_lite::future::block_on(
+                    self.func.call_async(&mut *store, &params, &mut results),
+                )
+                .map_err(call_error)?;
+                results
+                    .iter()
+                    .map(|result| value::to_js_value(result, store, &runtime))
+                    .collect::<JsResult<Vec<_>>>()
+            })
+            .ok_or_else(released_error)??;
+
+        match results.len() {
+            0 => Ok(JsValue::undefined()),
+            1 => Ok(results.swap_remove(0)),
+            _ => Ok(Array::create_array_from_list(results, context).into()),
+        }
+    }
+}
+
+/// The error thrown when the native instance backing an object is missing.
+fn released_error() -> JsNativeError {
+    JsNativeError::typ().with_message("WebAssembly.Instance has no backing instance")
+}
+
+/// The error thrown when calling an exported function fails.
+fn call_error(err: wasmtime::Error) -> JsError {
+    JsNativeError::typ()
+        .with_message(format!("WebAssembly function call failed: {err}"))
+        .into()
+}
+
 /// Internal data for WebAssembly.Instance instances
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
 pub struct WebAssemblyInstanceData {
     instance_id: String,
     store_id: String,
+    /// Releases the instance from the runtime once this object is collected.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
 }
 
 impl WebAssemblyInstanceData {
     pub fn new(instance_id: String, store_id: String) -> Self {
-        Self { instance_id, store_id }
+        Self { instance_id, store_id, guard: SharedGuard::default() }
     }
 
     pub fn instance_id(&self) -> &str {
@@ -11,10 +11,10 @@ use crate::{
     string::StaticJsStrings,
     value::JsValue,
     Context, JsArgs, JsData, JsNativeError, JsResult, js_string,
-    JsString, realm::Realm, property::Attribute
+    JsString, JsSymbol, realm::Realm, property::Attribute
 };
 use boa_gc::{Finalize, Trace};
-use super::runtime::WebAssemblyRuntime;
+use super::{brand_check_error, runtime::{Resource, SharedGuard, WebAssemblyRuntime}};
 
 /// JavaScript `WebAssembly.Memory` builtin implementation.
 #[derive(Debug, Copy, Clone)]
@@ -34,6 +34,11 @@ impl IntrinsicObject for WebAssemblyMemory {
                 Attribute::CONFIGURABLE,
             )
             .method(Self::grow, js_string!("grow"), 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Memory"),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -48,7 +53,7 @@ impl BuiltInObject for WebAssemblyMemory {
 
 impl BuiltInConstructor for WebAssemblyMemory {
     const LENGTH: usize = 1;
-    const P: usize = 2; // buffer property, grow method
+    const P: usize = 3; // buffer property, grow method, @@toStringTag
     const SP: usize = 0; // no static properties
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -97,25 +102,19 @@ impl WebAssemblyMemory {
             .to_u32(context)? as u64;
 
         // Get maximum pages (optional)
-        let maximum = if let Ok(max_val) = desc_obj.get(js_string!("maximum"), context) {
-            if !max_val.is_undefined() {
-                Some(max_val.to_u32(context)? as u64)
-            } else {
-                None
-            }
+        let max_val = desc_obj.get(js_string!("maximum"), context)?;
+        let maximum = if !max_val.is_undefined() {
+            Some(max_val.to_u32(context)? as u64)
         } else {
             None
         };
 
         // Get shared flag (optional, defaults to false)
-        let shared = if let Ok(shared_val) = desc_obj.get(js_string!("shared"), context) {
-            shared_val.to_boolean()
-        } else {
-            false
-        };
+        let shared = desc_obj.get(js_string!("shared"), context)?.to_boolean();
 
         // Get index type (optional, defaults to "i32")
-        let index = if let Ok(index_val) = desc_obj.get(js_string!("index"), context) {
+        let index_val = desc_obj.get(js_string!("index"), context)?;
+        let index = if !index_val.is_undefined() {
             let index_str = index_val.to_string(context)?;
             match index_str.to_std_string_escaped().as_str() {
                 "i32" => IndexType::I32,
@@ -196,10 +195,12 @@ impl WebAssemblyMemory {
             context,
         )?;
 
+        let mut memory_data = WebAssemblyMemoryData::new(memory_id.clone(), descriptor);
+        memory_data.guard = runtime.guard(Resource::Memory(memory_id));
         let memory_obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             proto,
-            WebAssemblyMemoryData::new(memory_id, descriptor),
+            memory_data,
         );
 
         Ok(memory_obj.into())
@@ -214,13 +215,11 @@ impl WebAssemblyMemory {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let memory_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Memory.buffer called on non-object")
+            brand_check_error("Memory", "buffer")
         })?;
 
         let memory_data = memory_obj.downcast_ref::<WebAssemblyMemoryData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Memory.buffer called on non-Memory object")
+            brand_check_error("Memory", "buffer")
         })?;
 
         // Get the runtime to access the memory
@@ -246,13 +245,11 @@ impl WebAssemblyMemory {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let memory_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Memory.grow called on non-object")
+            brand_check_error("Memory", "grow")
         })?;
 
         let memory_data = memory_obj.downcast_ref::<WebAssemblyMemoryData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Memory.grow called on non-Memory object")
+            brand_check_error("Memory", "grow")
         })?;
 
         let delta = args.get_or_undefined(0).to_u32(context)? as u64;
@@ -271,11 +268,14 @@ impl WebAssemblyMemory {
 pub struct WebAssemblyMemoryData {
     memory_id: String,
     descriptor: MemoryDescriptor,
+    /// Releases the memory from the runtime once this object is collected.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
 }
 
 impl WebAssemblyMemoryData {
     pub fn new(memory_id: String, descriptor: MemoryDescriptor) -> Self {
-        Self { memory_id, descriptor }
+        Self { memory_id, descriptor, guard: SharedGuard::default() }
     }
 
     pub fn memory_id(&self) -> &str {
@@ -13,16 +13,17 @@ pub(crate) mod memory;
 pub(crate) mod table;
 pub(crate) mod global;
 pub(crate) mod runtime;
+pub(crate) mod value;
 #[cfg(test)]
 mod tests;
 
 use crate::{
     builtins::{BuiltInObject, IntrinsicObject, BuiltInBuilder},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
-    object::JsObject,
+    object::{JsObject, builtins::JsPromise},
     string::StaticJsStrings,
     value::JsValue,
-    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, js_string,
+    Context, JsArgs, JsData, JsNativeError, JsResult, JsString, JsSymbol, js_string,
     realm::Realm, property::Attribute
 };
 
@@ -38,6 +39,17 @@ pub use table::WebAssemblyTable;
 pub use global::WebAssemblyGlobal;
 pub use runtime::WebAssemblyRuntime;
 
+/// Creates the `TypeError` thrown when a `WebAssembly` interface member is invoked on
+/// an object that doesn't carry the interface's internal slots.
+///
+/// The message mirrors the one produced for `#[boa_class]` platform objects so brand
+/// check failures read the same across every platform interface.
+pub(crate) fn brand_check_error(interface: &str, member: &str) -> JsNativeError {
+    JsNativeError::typ().with_message(format!(
+        "'{member}' called on an object that does not implement interface WebAssembly.{interface}."
+    ))
+}
+
 /// JavaScript `WebAssembly` global object implementation.
 #[derive(Debug, Copy, Clone)]
 pub(crate) struct WebAssembly;
@@ -56,8 +68,6 @@ impl IntrinsicObject for WebAssembly {
             .static_method(Self::validate, js_string!("validate"), 1)
             .static_method(Self::compile, js_string!("compile"), 1)
             .static_method(Self::instantiate, js_string!("instantiate"), 1)
-            .static_method(Self::compile_streaming, js_string!("compileStreaming"), 1)
-            .static_method(Self::instantiate_streaming, js_string!("instantiateStreaming"), 1)
             .static_property(
                 js_string!("Module"),
                 WebAssemblyModule::get(realm.intrinsics()),
@@ -83,12 +93,16 @@ impl IntrinsicObject for WebAssembly {
                 WebAssemblyGlobal::get(realm.intrinsics()),
                 Attribute::WRITABLE | Attribute::CONFIGURABLE,
             )
+            .static_property(
+                JsSymbol::to_string_tag(),
+                Self::NAME,
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
-    fn get(_intrinsics: &Intrinsics) -> JsObject {
-        // This should not be called as WebAssembly is not a constructor
-        JsObject::default()
+    fn get(intrinsics: &Intrinsics) -> JsObject {
+        intrinsics.objects().webassembly()
     }
 }
 
@@ -140,28 +154,21 @@ impl WebAssembly {
         args: &[JsValue],
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let bytes = Self::extract_bytes_from_buffer_source(args.get_or_undefined(0), context)?;
+        // Compilation happens synchronously for now, but the promise is still settled on a
+        // microtask so scripts observe the same ordering as with a truly async compilation.
+        let result = Self::extract_bytes_from_buffer_source(args.get_or_undefined(0), context)
+            .and_then(|bytes| WebAssemblyModule::compile_bytes(&bytes, context));
 
-        // Create a Promise for async compilation
-        let promise_constructor = context.intrinsics().constructors().promise().constructor();
-
-        // For now, we'll do synchronous compilation and return a resolved promise
-        // In a full implementation, this should be truly async
-        match WebAssemblyModule::compile_bytes(&bytes, context) {
-            Ok(module_obj) => {
-                crate::builtins::Promise::resolve(&promise_constructor.into(), &[module_obj], context)
-            }
-            Err(err) => {
-                let error_val = JsValue::from(js_string!(err.to_string()));
-                crate::builtins::Promise::reject(&promise_constructor.into(), &[error_val], context)
-            }
-        }
+        Ok(JsPromise::from_result_deferred(result, context).into())
     }
 
     /// `WebAssembly.instantiate(moduleObject, importObject)`
     /// `WebAssembly.instantiate(bytes, importObject)`
     ///
     /// The primary API for compiling and instantiating WebAssembly code.
+    ///
+    /// Called with a `Module`, the promise resolves to the new `Instance`. Called with
+    /// bytes, it resolves to a `{ module, instance }` result object.
     fn instantiate(
         _this: &JsValue,
         args: &[JsValue],
@@ -170,95 +177,43 @@ impl WebAssembly {
         let first_arg = args.get_or_undefined(0);
         let import_object = args.get_or_undefined(1);
 
-        let promise_constructor = context.intrinsics().constructors().promise().constructor();
-
-        // Check if first argument is a Module or bytes
-        if let Some(module_obj) = first_arg.as_object() {
-            if module_obj.is::<module::WebAssemblyModuleData>() {
-                // Instantiate from existing module
-                match WebAssemblyInstance::from_module(module_obj.clone(), import_object, context) {
-                    Ok(instance_obj) => {
-                        crate::builtins::Promise::resolve(&promise_constructor.into(), &[instance_obj], context)
-                    }
-                    Err(err) => {
-                        let error_val = JsValue::from(js_string!(err.to_string()));
-                        crate::builtins::Promise::reject(&promise_constructor.into(), &[error_val], context)
-                    }
+        // The import object is validated before compiling anything, so a bad import
+        // object rejects without paying for the compilation
+        let result = WebAssemblyInstance::validate_import_object(import_object).and_then(|_| {
+            match first_arg.as_object() {
+                // Instantiate from existing module, resolving to the instance itself
+                Some(module_obj) if module_obj.is::<module::WebAssemblyModuleData>() => {
+                    WebAssemblyInstance::from_module(module_obj.clone(), import_object, context)
                 }
-            } else {
-                // Treat as bytes
-                let bytes = Self::extract_bytes_from_buffer_source(first_arg, context)?;
-                Self::compile_and_instantiate(&bytes, import_object, context)
+                // Treat as bytes, resolving to `{ module, instance }`
+                _ => Self::extract_bytes_from_buffer_source(first_arg, context).and_then(
+                    |bytes| Self::compile_and_instantiate(&bytes, import_object, context),
+                ),
             }
-        } else {
-            // Treat as bytes
-            let bytes = Self::extract_bytes_from_buffer_source(first_arg, context)?;
-            Self::compile_and_instantiate(&bytes, import_object, context)
-        }
-    }
-
-    /// `WebAssembly.compileStreaming(source)`
-    ///
-    /// Compiles a WebAssembly.Module directly from a streamed underlying source.
-    fn compile_streaming(
-        _this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        let _source = args.get_or_undefined(0);
+        });
 
-        // TODO: Implement actual streaming compilation
-        // For now, return a rejected promise indicating not implemented
-        let promise_constructor = context.intrinsics().constructors().promise().constructor();
-        let error_val = JsValue::from(js_string!("compileStreaming not yet implemented"));
-        crate::builtins::Promise::reject(&promise_constructor.into(), &[error_val], context)
+        Ok(JsPromise::from_result_deferred(result, context).into())
     }
 
-    /// `WebAssembly.instantiateStreaming(source, importObject)`
-    ///
-    /// The primary API for compiling and instantiating a WebAssembly module
-    /// directly from a streamed underlying source.
-    fn instantiate_streaming(
-        _this: &JsValue,
-        args: &[JsValue],
-        context: &mut Context,
-    ) -> JsResult<JsValue> {
-        let _source = args.get_or_undefined(0);
-        let _import_object = args.get_or_undefined(1);
-
-        // TODO: Implement actual streaming instantiation
-        // For now, return a rejected promise indicating not implemented
-        let promise_constructor = context.intrinsics().constructors().promise().constructor();
-        let error_val = JsValue::from(js_string!("instantiateStreaming not yet implemented"));
-        crate::builtins::Promise::reject(&promise_constructor.into(), &[error_val], context)
-    }
-
-    /// Helper function to compile and instantiate WebAssembly bytes
+    /// Helper function to compile and instantiate WebAssembly bytes, returning the
+    /// `{ module, instance }` result object
     fn compile_and_instantiate(
         bytes: &[u8],
         import_object: &JsValue,
         context: &mut Context,
     ) -> JsResult<JsValue> {
-        let promise_constructor = context.intrinsics().constructors().promise().constructor();
-
         // First compile the module
         let module_obj = WebAssemblyModule::compile_bytes(bytes, context)?;
 
         // Then instantiate it
-        match WebAssemblyInstance::from_module(module_obj.as_object().unwrap().clone(), import_object, context) {
-            Ok(instance_obj) => {
-                // Create result object with both module and instance
-                let result_obj = JsObject::with_object_proto(context.intrinsics());
-                result_obj.set(js_string!("module"), module_obj, false, context)?;
-                result_obj.set(js_string!("instance"), instance_obj, false, context)?;
+        let instance_obj = WebAssemblyInstance::from_module(module_obj.as_object().unwrap().clone(), import_object, context)?;
 
-                crate::builtins::Promise::resolve(&promise_constructor.into(), &[result_obj.into()], context)
-            }
-            Err(err) => {
-                let error_val = JsValue::from(js_string!(err.to_string()));
-                crate::builtins::Promise::reject(&promise_constructor.into(), &[error_val], context)
-            }
-        }
+        // Create result object with both module and instance
+        let result_obj = JsObject::with_object_proto(context.intrinsics());
+        result_obj.set(js_string!("module"), module_obj, false, context)?;
+        result_obj.set(js_string!("instance"), instance_obj, false, context)?;
+
+        Ok(result_obj.into())
     }
 
     /// Helper function to extract bytes from a BufferSource (ArrayBuffer or TypedArray)
@@ -8,13 +8,13 @@ use crate::{
     builtins::{BuiltInObject, IntrinsicObject, BuiltInConstructor, BuiltInBuilder},
     context::intrinsics::{Intrinsics, StandardConstructor, StandardConstructors},
     object::{internal_methods::get_prototype_from_constructor, JsObject, JsArray},
-    string::StaticJsStrings,
+    string::{JsStr, StaticJsStrings},
     value::JsValue,
     Context, JsArgs, JsData, JsNativeError, JsResult, js_string,
-    JsString, realm::Realm, property::Attribute
+    JsString, JsSymbol, realm::Realm, property::Attribute
 };
 use boa_gc::{Finalize, Trace};
-use super::runtime::WebAssemblyRuntime;
+use super::runtime::{Resource, SharedGuard, WebAssemblyRuntime};
 
 /// JavaScript `WebAssembly.Module` builtin implementation.
 #[derive(Debug, Copy, Clone)]
@@ -26,6 +26,11 @@ impl IntrinsicObject for WebAssemblyModule {
             .static_method(Self::exports, js_string!("exports"), 1)
             .static_method(Self::imports, js_string!("imports"), 1)
             .static_method(Self::custom_sections, js_string!("customSections"), 2)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Module"),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -40,7 +45,7 @@ impl BuiltInObject for WebAssemblyModule {
 
 impl BuiltInConstructor for WebAssemblyModule {
     const LENGTH: usize = 1;
-    const P: usize = 0; // no prototype properties
+    const P: usize = 1; // @@toStringTag
     const SP: usize = 3; // exports, imports, customSections static methods
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -91,10 +96,12 @@ impl WebAssemblyModule {
             context,
         )?;
 
+        let mut module_data = WebAssemblyModuleData::new(module_id.clone());
+        module_data.guard = runtime.guard(Resource::Module(module_id));
         let module_obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             proto,
-            WebAssemblyModuleData::new(module_id),
+            module_data,
         );
 
         Ok(module_obj.into())
@@ -135,15 +142,9 @@ impl WebAssemblyModule {
 
         for export in module.exports() {
             let export_descriptor = JsObject::with_object_proto(context.intrinsics());
-            export_descriptor.set(js_string!("name"), js_string!(export.name()), false, context)?;
+            export_descriptor.set(js_string!("name"), intern_name(export.name()), false, context)?;
 
-            let kind = match export.ty() {
-                wasmtime::ExternType::Func(_) => "function",
-                wasmtime::ExternType::Table(_) => "table",
-                wasmtime::ExternType::Memory(_) => "memory",
-                wasmtime::ExternType::Global(_) => "global",
-            };
-            export_descriptor.set(js_string!("kind"), js_string!(kind), false, context)?;
+            export_descriptor.set(js_string!("kind"), extern_kind(&export.ty()), false, context)?;
 
             exports_array.set(index, export_descriptor, true, context)?;
             index += 1;
@@ -187,16 +188,10 @@ impl WebAssemblyModule {
 
         for import in module.imports() {
             let import_descriptor = JsObject::with_object_proto(context.intrinsics());
-            import_descriptor.set(js_string!("module"), js_string!(import.module()), false, context)?;
-            import_descriptor.set(js_string!("name"), js_string!(import.name()), false, context)?;
+            import_descriptor.set(js_string!("module"), intern_name(import.module()), false, context)?;
+            import_descriptor.set(js_string!("name"), intern_name(import.name()), false, context)?;
 
-            let kind = match import.ty() {
-                wasmtime::ExternType::Func(_) => "function",
-                wasmtime::ExternType::Table(_) => "table",
-                wasmtime::ExternType::Memory(_) => "memory",
-                wasmtime::ExternType::Global(_) => "global",
-            };
-            import_descriptor.set(js_string!("kind"), js_string!(kind), false, context)?;
+            import_descriptor.set(js_string!("kind"), extern_kind(&import.ty()), false, context)?;
 
             imports_array.set(index, import_descriptor, true, context)?;
             index += 1;
@@ -241,11 +236,14 @@ impl WebAssemblyModule {
 #[derive(Debug, Clone, Trace, Finalize, JsData)]
 pub struct WebAssemblyModuleData {
     module_id: String,
+    /// Releases the module from the runtime once this object is collected.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
 }
 
 impl WebAssemblyModuleData {
     pub fn new(module_id: String) -> Self {
-        Self { module_id }
+        Self { module_id, guard: SharedGuard::default() }
     }
 
     pub fn module_id(&self) -> &str {
@@ -253,6 +251,30 @@ impl WebAssemblyModuleData {
     }
 }
 
+/// Returns the `kind` of an import or export descriptor.
+///
+/// The set of kinds is closed, so every descriptor shares the same static strings instead
+/// of allocating a new one per entry.
+fn extern_kind(ty: &wasmtime::ExternType) -> JsString {
+    match ty {
+        wasmtime::ExternType::Func(_) => js_string!("function"),
+        wasmtime::ExternType::Table(_) => js_string!("table"),
+        wasmtime::ExternType::Memory(_) => js_string!("memory"),
+        wasmtime::ExternType::Global(_) => js_string!("global"),
+    }
+}
+
+/// Converts an import or export name into a `JsString`, reusing the engine's static
+/// string table for common names (`memory`, `table`, `length`, ...) before allocating.
+fn intern_name(name: &str) -> JsString {
+    if name.is_ascii() {
+        if let Some(string) = StaticJsStrings::get_string(&JsStr::latin1(name.as_bytes())) {
+            return string;
+        }
+    }
+    JsString::from(name)
+}
+
 /// Helper function to extract bytes from a BufferSource (ArrayBuffer or TypedArray)
 fn extract_bytes_from_buffer_source(
     buffer_source: &JsValue,
@@ -3,16 +3,22 @@
 //! This module provides the runtime infrastructure for executing WebAssembly
 //! modules, managing engines, stores, and compiled modules.
 
-use crate::{Context, JsResult, JsNativeError, JsData};
-use boa_gc::{Finalize, Trace};
+use super::value::WebAssemblyValue;
+use crate::{Context, JsResult, JsNativeError, JsData, JsValue};
+use boa_gc::{Finalize, Gc, GcRefCell, Trace};
+use std::cell::RefCell;
 use std::collections::HashMap;
+use std::mem;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, OnceLock};
 use wasmtime::*;
 
-/// Global WebAssembly runtime manager
+/// WebAssembly runtime manager
 ///
-/// This provides a singleton runtime that manages the wasmtime Engine,
-/// compiled modules, instances, and stores for the entire Boa context.
+/// This manages the wasmtime Engine, compiled modules, instances, and stores
+/// for a single Boa context. It is created lazily the first time a script uses
+/// the `WebAssembly` API and stored in the context's host-defined data.
 #[derive(Clone, Trace, Finalize, JsData)]
 pub struct WebAssemblyRuntime {
     #[unsafe_ignore_trace]
@@ -29,9 +35,67 @@ pub struct WebAssemblyRuntime {
     tables: Arc<Mutex<HashMap<String, Table>>>,
     #[unsafe_ignore_trace]
     globals: Arc<Mutex<HashMap<String, Global>>>,
+    /// The stores created for standalone memories, tables and globals, by the id of the
+    /// extern they were created for.
+    #[unsafe_ignore_trace]
+    extern_stores: Arc<Mutex<HashMap<String, String>>>,
+    /// The JavaScript values passed to WebAssembly as `externref`s, indexed by the host
+    /// data of the references wrapping them.
+    extern_values: Gc<GcRefCell<Vec<JsValue>>>,
+    /// The resources whose objects were collected, waiting to be released outside of
+    /// garbage collection.
+    #[unsafe_ignore_trace]
+    collected: Rc<RefCell<Vec<Resource>>>,
+    /// The counter used to generate resource IDs.
+    #[unsafe_ignore_trace]
+    next_id: Arc<AtomicU64>,
+}
+
+/// A native resource held by the runtime on behalf of a `WebAssembly` object.
+#[derive(Debug, Clone)]
+pub(crate) enum Resource {
+    Module(String),
+    Instance { instance_id: String, store_id: String },
+    Memory(String),
+    Table(String),
+    Global(String),
+}
+
+/// Releases a native resource from the runtime once dropped.
+///
+/// Guards are stored in the internal data of the object owning the resource, so the
+/// resource lives exactly as long as the object: when the garbage collector frees the
+/// last reference to the object, the guard is dropped along with it and queues the
+/// resource for release. Releasing locks the maps of the runtime, so it doesn't happen
+/// during garbage collection, but the next time the runtime is retrieved, which is when
+/// the wasmtime module, store or extern is removed from the runtime.
+#[derive(Debug)]
+pub(crate) struct ResourceGuard {
+    runtime: WebAssemblyRuntime,
+    resource: Resource,
 }
 
-static RUNTIME: OnceLock<WebAssemblyRuntime> = OnceLock::new();
+/// The guard of a native resource, shared by the clones of the internal data of the
+/// object owning the resource. The default value doesn't own any resource.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SharedGuard(Option<Rc<ResourceGuard>>);
+
+impl Drop for ResourceGuard {
+    fn drop(&mut self) {
+        self.runtime.collected.borrow_mut().push(self.resource.clone());
+    }
+}
+
+/// Removes `id` from `map`, ignoring poisoned locks.
+fn remove_entry<V>(map: &Mutex<HashMap<String, V>>, id: &str) -> Option<V> {
+    map.lock().ok().and_then(|mut map| map.remove(id))
+}
+
+/// The wasmtime engine, shared by the runtimes of every context.
+///
+/// Engines are thread-safe and expensive to configure, so the engine is built once,
+/// on first use, instead of once per context.
+static ENGINE: OnceLock<Arc<Engine>> = OnceLock::new();
 
 impl std::fmt::Debug for WebAssemblyRuntime {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -42,13 +106,31 @@ impl std::fmt::Debug for WebAssemblyRuntime {
             .field("memories", &self.memories)
             .field("tables", &self.tables)
             .field("globals", &self.globals)
+            .field("extern_stores", &self.extern_stores)
             .finish()
     }
 }
 
 impl WebAssemblyRuntime {
-    /// Create a new WebAssembly runtime with optimized configuration
+    /// Create a new WebAssembly runtime on top of the shared engine
     fn new() -> Self {
+        Self {
+            engine: ENGINE.get_or_init(Self::create_engine).clone(),
+            modules: Arc::new(Mutex::new(HashMap::new())),
+            instances: Arc::new(Mutex::new(HashMap::new())),
+            stores: Arc::new(Mutex::new(HashMap::new())),
+            memories: Arc::new(Mutex::new(HashMap::new())),
+            tables: Arc::new(Mutex::new(HashMap::new())),
+            globals: Arc::new(Mutex::new(HashMap::new())),
+            extern_stores: Arc::new(Mutex::new(HashMap::new())),
+            extern_values: Gc::new(GcRefCell::new(Vec::new())),
+            collected: Rc::default(),
+            next_id: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Create the wasmtime engine with optimized configuration
+    fn create_engine() -> Arc<Engine> {
         // Configure wasmtime engine with optimal settings for web compatibility
         let mut config = Config::new();
         config.wasm_component_model(true);
@@ -65,22 +147,23 @@ impl WebAssemblyRuntime {
         config.wasm_multi_value(true);
         config.cranelift_opt_level(OptLevel::Speed);
 
-        let engine = Arc::new(Engine::new(&config).expect("Failed to create WebAssembly engine"));
+        Arc::new(Engine::new(&config).expect("Failed to create WebAssembly engine"))
+    }
 
-        Self {
-            engine,
-            modules: Arc::new(Mutex::new(HashMap::new())),
-            instances: Arc::new(Mutex::new(HashMap::new())),
-            stores: Arc::new(Mutex::new(HashMap::new())),
-            memories: Arc::new(Mutex::new(HashMap::new())),
-            tables: Arc::new(Mutex::new(HashMap::new())),
-            globals: Arc::new(Mutex::new(HashMap::new())),
+    /// Get the WebAssembly runtime of `context`, creating it on first use
+    ///
+    /// Contexts that never touch the `WebAssembly` API never build a runtime,
+    /// keeping context creation cheap. The resources of the objects collected since
+    /// the last call are released first.
+    pub fn get_or_create(context: &mut Context) -> JsResult<WebAssemblyRuntime> {
+        if let Some(runtime) = context.get_data::<Self>() {
+            runtime.release_collected();
+            return Ok(runtime.clone());
         }
-    }
 
-    /// Get or create the global WebAssembly runtime
-    pub fn get_or_create(_context: &mut Context) -> JsResult<&'static WebAssemblyRuntime> {
-        Ok(RUNTIME.get_or_init(Self::new))
+        let runtime = Self::new();
+        context.insert_data(runtime.clone());
+        Ok(runtime)
     }
 
     /// Get the wasmtime engine
@@ -121,6 +204,20 @@ impl WebAssemblyRuntime {
             .map(f)
     }
 
+    /// Get the store of a standalone memory, table or global (mutable access)
+    pub fn with_extern_store_mut<F, R>(&self, extern_id: &str, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut Store<()>) -> R,
+    {
+        let store_id = self
+            .extern_stores
+            .lock()
+            .expect("extern stores lock poisoned")
+            .get(extern_id)
+            .cloned()?;
+        self.with_store_mut(&store_id, f)
+    }
+
     /// Instantiate a module with imports
     pub fn instantiate_module(
         &self,
@@ -159,7 +256,9 @@ impl WebAssemblyRuntime {
         }
 
         self.with_store_mut(&store_id, |store| {
-            let instance = Instance::new(store, &module, &import_vec)?;
+            // The engine enables async support, which rules out the synchronous instantiation.
+            let instance =
+                futures_lite::future::block_on(Instance::new_async(store, &module, &import_vec))?;
             self.instances.lock().unwrap().insert(instance_id.clone(), instance);
             Ok(instance_id)
         })
@@ -179,6 +278,10 @@ impl WebAssemblyRuntime {
         self.with_store_mut(&store_id, |store| {
             let memory = Memory::new(store, memory_type)?;
             self.memories.lock().unwrap().insert(memory_id.clone(), memory);
+            self.extern_stores
+                .lock()
+                .expect("extern stores lock poisoned")
+                .insert(memory_id.clone(), store_id.clone());
             Ok(memory_id)
         })
         .unwrap_or_else(|| Err(wasmtime::Error::msg("Failed to create store")))
@@ -197,6 +300,10 @@ impl WebAssemblyRuntime {
         self.with_store_mut(&store_id, |store| {
             let table = Table::new(store, table_type, init)?;
             self.tables.lock().unwrap().insert(table_id.clone(), table);
+            self.extern_stores
+                .lock()
+                .expect("extern stores lock poisoned")
+                .insert(table_id.clone(), store_id.clone());
             Ok(table_id)
         })
         .unwrap_or_else(|| Err(wasmtime::Error::msg("Failed to create store")))
@@ -208,13 +315,18 @@ impl WebAssemblyRuntime {
     }
 
     /// Create a WebAssembly global
-    pub fn create_global(&self, global_type: GlobalType, init: wasmtime::Val) -> Result<String, wasmtime::Error> {
+    pub(crate) fn create_global(&self, global_type: GlobalType, init: WebAssemblyValue) -> Result<String, wasmtime::Error> {
         let store_id = self.create_store();
         let global_id = self.generate_global_id();
 
         self.with_store_mut(&store_id, |store| {
+            let init = init.into_val(store, self)?;
             let global = Global::new(store, global_type, init)?;
             self.globals.lock().unwrap().insert(global_id.clone(), global);
+            self.extern_stores
+                .lock()
+                .expect("extern stores lock poisoned")
+                .insert(global_id.clone(), store_id.clone());
             Ok(global_id)
         })
         .unwrap_or_else(|| Err(wasmtime::Error::msg("Failed to create store")))
@@ -225,6 +337,80 @@ impl WebAssemblyRuntime {
         self.globals.lock().unwrap().get(global_id).cloned()
     }
 
+    /// Hold `value` for an `externref` wrapping it, returning its index
+    ///
+    /// Wasmtime requires the host data of an `externref` to be thread-safe, which
+    /// JavaScript values aren't, so references wrap an index into the values held by
+    /// the runtime instead. The values are held as long as the runtime.
+    pub(crate) fn hold_extern_value(&self, value: JsValue) -> usize {
+        let mut values = self.extern_values.borrow_mut();
+        values.push(value);
+        values.len() - 1
+    }
+
+    /// Get the value held for an `externref` by its index
+    pub(crate) fn extern_value(&self, index: usize) -> Option<JsValue> {
+        self.extern_values.borrow().get(index).cloned()
+    }
+
+    /// Create a guard releasing `resource` from the runtime when dropped
+    pub(crate) fn guard(&self, resource: Resource) -> SharedGuard {
+        SharedGuard(Some(Rc::new(ResourceGuard {
+            runtime: self.clone(),
+            resource,
+        })))
+    }
+
+    /// Release the resources of the objects collected by the garbage collector
+    fn release_collected(&self) {
+        let collected = mem::take(&mut *self.collected.borrow_mut());
+        for resource in &collected {
+            self.release(resource);
+        }
+    }
+
+    /// Release a native resource, dropping the wasmtime objects backing it
+    fn release(&self, resource: &Resource) {
+        let extern_id = match resource {
+            Resource::Module(module_id) => {
+                remove_entry(&self.modules, module_id);
+                return;
+            }
+            Resource::Instance { instance_id, store_id } => {
+                remove_entry(&self.instances, instance_id);
+                remove_entry(&self.stores, store_id);
+                return;
+            }
+            Resource::Memory(memory_id) => {
+                remove_entry(&self.memories, memory_id);
+                memory_id
+            }
+            Resource::Table(table_id) => {
+                remove_entry(&self.tables, table_id);
+                table_id
+            }
+            Resource::Global(global_id) => {
+                remove_entry(&self.globals, global_id);
+                global_id
+            }
+        };
+
+        if let Some(store_id) = remove_entry(&self.extern_stores, extern_id) {
+            remove_entry(&self.stores, &store_id);
+        }
+    }
+
+    /// Get the number of native resources currently held by the runtime
+    #[cfg(test)]
+    pub(crate) fn resource_count(&self) -> usize {
+        self.modules.lock().unwrap().len()
+            + self.instances.lock().unwrap().len()
+            + self.stores.lock().unwrap().len()
+            + self.memories.lock().unwrap().len()
+            + self.tables.lock().unwrap().len()
+            + self.globals.lock().unwrap().len()
+    }
+
     /// Generate a unique module ID
     fn generate_module_id(&self) -> String {
         format!("module_{}", self.generate_unique_id())
@@ -255,19 +441,12 @@ impl WebAssemblyRuntime {
         format!("global_{}", self.generate_unique_id())
     }
 
-    /// Generate a unique ID using random number
+    /// Generate a unique ID
+    ///
+    /// IDs come from a counter instead of the system time, so two resources created in the
+    /// same clock tick can never share an ID.
     fn generate_unique_id(&self) -> u64 {
-        use std::collections::hash_map::DefaultHasher;
-        use std::hash::{Hash, Hasher};
-        use std::time::{SystemTime, UNIX_EPOCH};
-
-        let mut hasher = DefaultHasher::new();
-        SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_nanos()
-            .hash(&mut hasher);
-        hasher.finish()
+        self.next_id.fetch_add(1, Ordering::Relaxed)
     }
 
     /// Clean up resources (called when context is dropped)
@@ -278,5 +457,6 @@ impl WebAssemblyRuntime {
         self.memories.lock().unwrap().clear();
         self.tables.lock().unwrap().clear();
         self.globals.lock().unwrap().clear();
+        self.extern_stores.lock().expect("extern stores lock poisoned").clear();
     }
 }
\ No newline at end of file
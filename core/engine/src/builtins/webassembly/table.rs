@@ -11,10 +11,10 @@ use crate::{
     string::StaticJsStrings,
     value::JsValue,
     Context, JsArgs, JsData, JsNativeError, JsResult, js_string,
-    JsString, realm::Realm, property::Attribute
+    JsString, JsSymbol, realm::Realm, property::Attribute
 };
 use boa_gc::{Finalize, Trace};
-use super::runtime::WebAssemblyRuntime;
+use super::{brand_check_error, runtime::{Resource, SharedGuard, WebAssemblyRuntime}};
 
 /// JavaScript `WebAssembly.Table` builtin implementation.
 #[derive(Debug, Copy, Clone)]
@@ -36,6 +36,11 @@ impl IntrinsicObject for WebAssemblyTable {
             .method(Self::get, js_string!("get"), 1)
             .method(Self::set, js_string!("set"), 2)
             .method(Self::grow, js_string!("grow"), 1)
+            .property(
+                JsSymbol::to_string_tag(),
+                js_string!("WebAssembly.Table"),
+                Attribute::READONLY | Attribute::NON_ENUMERABLE | Attribute::CONFIGURABLE,
+            )
             .build();
     }
 
@@ -50,7 +55,7 @@ impl BuiltInObject for WebAssemblyTable {
 
 impl BuiltInConstructor for WebAssemblyTable {
     const LENGTH: usize = 1;
-    const P: usize = 4; // length property, get, set, grow methods
+    const P: usize = 5; // length property, get, set, grow methods, @@toStringTag
     const SP: usize = 0; // no static properties
 
     const STANDARD_CONSTRUCTOR: fn(&StandardConstructors) -> &StandardConstructor =
@@ -193,10 +198,12 @@ impl WebAssemblyTable {
             context,
         )?;
 
+        let mut table_data = WebAssemblyTableData::new(table_id.clone(), descriptor);
+        table_data.guard = runtime.guard(Resource::Table(table_id));
         let table_obj = JsObject::from_proto_and_data_with_shared_shape(
             context.root_shape(),
             proto,
-            WebAssemblyTableData::new(table_id, descriptor),
+            table_data,
         );
 
         Ok(table_obj.into())
@@ -211,13 +218,11 @@ impl WebAssemblyTable {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let table_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.length called on non-object")
+            brand_check_error("Table", "length")
         })?;
 
         let table_data = table_obj.downcast_ref::<WebAssemblyTableData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.length called on non-Table object")
+            brand_check_error("Table", "length")
         })?;
 
         // For now, return the initial size
@@ -234,13 +239,11 @@ impl WebAssemblyTable {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let table_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.get called on non-object")
+            brand_check_error("Table", "get")
         })?;
 
         let table_data = table_obj.downcast_ref::<WebAssemblyTableData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.get called on non-Table object")
+            brand_check_error("Table", "get")
         })?;
 
         let index = args.get_or_undefined(0).to_u32(context)?;
@@ -269,13 +272,11 @@ impl WebAssemblyTable {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let table_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.set called on non-object")
+            brand_check_error("Table", "set")
         })?;
 
         let table_data = table_obj.downcast_ref::<WebAssemblyTableData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.set called on non-Table object")
+            brand_check_error("Table", "set")
         })?;
 
         let index = args.get_or_undefined(0).to_u32(context)?;
@@ -316,13 +317,11 @@ impl WebAssemblyTable {
         context: &mut Context,
     ) -> JsResult<JsValue> {
         let table_obj = this.as_object().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.grow called on non-object")
+            brand_check_error("Table", "grow")
         })?;
 
         let table_data = table_obj.downcast_ref::<WebAssemblyTableData>().ok_or_else(|| {
-            JsNativeError::typ()
-                .with_message("WebAssembly.Table.grow called on non-Table object")
+            brand_check_error("Table", "grow")
         })?;
 
         let delta = args.get_or_undefined(0).to_u32(context)?;
@@ -342,11 +341,14 @@ impl WebAssemblyTable {
 pub struct WebAssemblyTableData {
     table_id: String,
     descriptor: TableDescriptor,
+    /// Releases the table from the runtime once this object is collected.
+    #[unsafe_ignore_trace]
+    guard: SharedGuard,
 }
 
 impl WebAssemblyTableData {
     pub fn new(table_id: String, descriptor: TableDescriptor) -> Self {
-        Self { table_id, descriptor }
+        Self { table_id, descriptor, guard: SharedGuard::default() }
     }
 
     pub fn table_id(&self) -> &str {
@@ -0,0 +1,195 @@
+//! Conversions between JavaScript and WebAssembly values
+//!
+//! Implementation of the `ToWebAssemblyValue` and `ToJSValue` algorithms of
+//! the W3C WebAssembly JavaScript API specification
+//! https://webassembly.github.io/spec/js-api/#towebassemblyvalue
+
+use super::runtime::WebAssemblyRuntime;
+use crate::{Context, JsBigInt, JsNativeError, JsResult, value::JsValue};
+use wasmtime::{ExternRef, Store, Val, ValType};
+
+/// The canonical `f32` NaN, with only the most significant fraction bit set.
+const CANONICAL_NAN_F32: u32 = 0x7fc0_0000;
+
+/// The canonical `f64` NaN, with only the most significant fraction bit set.
+const CANONICAL_NAN_F64: u64 = 0x7ff8_0000_0000_0000;
+
+/// A JavaScript value converted to a WebAssembly value
+///
+/// An `externref` wraps a host value allocated in the store it is passed to, so
+/// converting a non-null reference is completed by [`WebAssemblyValue::into_val`]
+/// once that store is known.
+#[derive(Debug)]
+pub(crate) enum WebAssemblyValue {
+    Val(Val),
+    ExternRef(JsValue),
+}
+
+/// The host data of an `externref` wrapping a JavaScript value, indexing the values
+/// held by the runtime.
+#[derive(Debug, Clone, Copy)]
+struct ExternValue(usize);
+
+impl WebAssemblyValue {
+    /// Get the WebAssembly value, allocating `externref`s in `store`
+    pub(crate) fn into_val(
+        self,
+        store: &mut Store<()>,
+        runtime: &WebAssemblyRuntime,
+    ) -> wasmtime::Result<Val> {
+        match self {
+            Self::Val(value) => Ok(value),
+            Self::ExternRef(value) => {
+                let index = runtime.hold_extern_value(value);
+                let extern_ref = ExternRef::new(store, ExternValue(index))?;
+                Ok(Val::ExternRef(Some(extern_ref)))
+            }
+        }
+    }
+}
+
+impl From<Val> for WebAssemblyValue {
+    fn from(value: Val) -> Self {
+        Self::Val(value)
+    }
+}
+
+/// Convert a JavaScript value to a WebAssembly value of type `ty`
+///
+/// NaNs are canonicalized, so the bits seen by WebAssembly don't depend on how
+/// the NaN was produced in JavaScript. Any JavaScript value can be passed as an
+/// `externref`.
+///
+/// More information:
+///  - [WebAssembly JS API specification][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#towebassemblyvalue
+pub(crate) fn to_webassembly_value(
+    value: &JsValue,
+    ty: &ValType,
+    context: &mut Context,
+) -> JsResult<WebAssemblyValue> {
+    let value = match ty {
+        // 1. If type is i64, return i64.const ? ToBigInt64(v).
+        ValType::I64 => Val::I64(value.to_big_int64(context)?),
+        // 2. If type is i32, return i32.const ? ToInt32(v).
+        ValType::I32 => Val::I32(value.to_i32(context)?),
+        // 3. If type is f32, return f32.const of ? ToNumber(v) rounded to the nearest
+        //    representable value, using IEEE 754-2019 round to nearest, ties to even.
+        ValType::F32 => {
+            let number = value.to_number(context)?;
+            if number.is_nan() {
+                Val::F32(CANONICAL_NAN_F32)
+            } else {
+                #[allow(clippy::cast_possible_truncation)]
+                let number = number as f32;
+                Val::F32(number.to_bits())
+            }
+        }
+        // 4. If type is f64, return f64.const ? ToNumber(v).
+        ValType::F64 => {
+            let number = value.to_number(context)?;
+            if number.is_nan() {
+                Val::F64(CANONICAL_NAN_F64)
+            } else {
+                Val::F64(number.to_bits())
+            }
+        }
+        // 5. If type is v128, throw a TypeError.
+        ValType::V128 => {
+            return Err(JsNativeError::typ()
+                .with_message("cannot convert a JavaScript value to a WebAssembly v128")
+                .into());
+        }
+        // 6. If type is a reference type and v is null, return the null reference.
+        ValType::Ref(_) if value.is_null() => null_reference(ty)?,
+        ValType::Ref(_) if ty.is_funcref() => {
+            return Err(JsNativeError::typ()
+                .with_message("funcref values must be null or an exported WebAssembly function")
+                .into());
+        }
+        // 7. If type is externref, return the reference to the host value v.
+        ValType::Ref(_) if ty.is_externref() => {
+            return Ok(WebAssemblyValue::ExternRef(value.clone()));
+        }
+        ValType::Ref(_) => {
+            return Err(JsNativeError::typ()
+                .with_message("unsupported WebAssembly reference type")
+                .into());
+        }
+    };
+    Ok(value.into())
+}
+
+/// Convert a WebAssembly value read from `store` to a JavaScript value
+///
+/// More information:
+///  - [WebAssembly JS API specification][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#tojsvalue
+pub(crate) fn to_js_value(
+    value: &Val,
+    store: &Store<()>,
+    runtime: &WebAssemblyRuntime,
+) -> JsResult<JsValue> {
+    match value {
+        // 1. If w is of the form i64.const u64, return ℤ(signed_64(u64)) as a BigInt.
+        Val::I64(int) => Ok(JsBigInt::from(*int).into()),
+        // 2. If w is of the form i32.const u32, return 𝔽(signed_32(u32)).
+        Val::I32(int) => Ok(JsValue::new(*int)),
+        // 3. If w is of the form f32.const f32, return 𝔽(f32), with NaNs canonicalized.
+        Val::F32(bits) => Ok(JsValue::new(f64::from(f32::from_bits(*bits)))),
+        // 4. If w is of the form f64.const f64, return 𝔽(f64), with NaNs canonicalized.
+        Val::F64(bits) => Ok(JsValue::new(f64::from_bits(*bits))),
+        // 5. If w is of the form v128.const, throw a TypeError.
+        Val::V128(_) => Err(JsNativeError::typ()
+            .with_message("cannot convert a WebAssembly v128 to a JavaScript value")
+            .into()),
+        // 6. If w is a null reference, return null.
+        Val::FuncRef(None) | Val::ExternRef(None) => Ok(JsValue::null()),
+        // 7. If w is an externref wrapping a host value, return that value.
+        Val::ExternRef(Some(extern_ref)) => extern_ref
+            .data(store)
+            .ok()
+            .and_then(|data| data.downcast_ref::<ExternValue>())
+            .and_then(|&ExternValue(index)| runtime.extern_value(index))
+            .ok_or_else(|| {
+                JsNativeError::typ()
+                    .with_message("externref does not wrap a JavaScript value")
+                    .into()
+            }),
+        _ => Err(JsNativeError::typ()
+            .with_message("non-null WebAssembly function references are not supported yet")
+            .into()),
+    }
+}
+
+/// The default value of a WebAssembly value type
+///
+/// More information:
+///  - [WebAssembly JS API specification][spec]
+///
+/// [spec]: https://webassembly.github.io/spec/js-api/#defaultvalue
+pub(crate) fn default_value(ty: &ValType) -> JsResult<Val> {
+    match ty {
+        ValType::I32 => Ok(Val::I32(0)),
+        ValType::I64 => Ok(Val::I64(0)),
+        ValType::F32 => Ok(Val::F32(0)),
+        ValType::F64 => Ok(Val::F64(0)),
+        ValType::V128 => Ok(Val::V128(0.into())),
+        ValType::Ref(_) => null_reference(ty),
+    }
+}
+
+/// The null reference of a reference type
+fn null_reference(ty: &ValType) -> JsResult<Val> {
+    if ty.is_funcref() {
+        Ok(Val::null_func_ref())
+    } else if ty.is_externref() {
+        Ok(Val::null_extern_ref())
+    } else {
+        Err(JsNativeError::typ()
+            .with_message("unsupported WebAssembly reference type")
+            .into())
+    }
+}
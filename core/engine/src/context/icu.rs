@@ -1,7 +1,7 @@
 use std::{cell::OnceCell, fmt::Debug};
 
 use icu_casemap::CaseMapper;
-use icu_locale::{LocaleCanonicalizer, LocaleExpander};
+use icu_locale::{Locale, LocaleCanonicalizer, LocaleExpander};
 use icu_normalizer::{ComposingNormalizer, DecomposingNormalizer};
 use icu_provider::prelude::*;
 use serde::Deserialize;
@@ -40,6 +40,7 @@ pub(crate) struct IntlProvider {
     locale_expander: OnceCell<LocaleExpander>,
     string_normalizers: OnceCell<StringNormalizers>,
     case_mapper: OnceCell<CaseMapper>,
+    default_locale: Option<Locale>,
 }
 
 impl<M> DataProvider<M> for IntlProvider
@@ -73,6 +74,7 @@ impl Debug for IntlProvider {
             .field("locale_expander", &self.locale_expander)
             .field("string_normalizers", &self.string_normalizers)
             .field("string_normalizercase_mapper", &self.case_mapper)
+            .field("default_locale", &self.default_locale)
             .finish_non_exhaustive()
     }
 }
@@ -91,10 +93,21 @@ impl IntlProvider {
             locale_expander: OnceCell::new(),
             string_normalizers: OnceCell::new(),
             case_mapper: OnceCell::new(),
+            default_locale: None,
             inner_provider: Box::new(provider),
         }
     }
 
+    /// Gets the locale overriding the locale of the system as the default locale.
+    pub(crate) const fn default_locale(&self) -> Option<&Locale> {
+        self.default_locale.as_ref()
+    }
+
+    /// Sets the locale overriding the locale of the system as the default locale.
+    pub(crate) fn set_default_locale(&mut self, locale: Option<Locale>) {
+        self.default_locale = locale;
+    }
+
     /// Gets the [`LocaleCanonicalizer`] tool.
     pub(crate) fn locale_canonicalizer(&self) -> Result<&LocaleCanonicalizer, IcuError> {
         if let Some(lc) = self.locale_canonicalizer.get() {
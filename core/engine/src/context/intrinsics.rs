@@ -155,6 +155,11 @@ impl Intrinsics {
         {
             builtins::temporal::Temporal::init(realm);
         }
+
+        #[cfg(feature = "webassembly")]
+        {
+            builtins::webassembly::WebAssembly::init(realm);
+        }
     }
 }
 
@@ -2235,6 +2240,10 @@ pub struct IntrinsicObjects {
     #[cfg(feature = "temporal")]
     now: JsObject,
 
+    /// [`%WebAssembly%`](https://webassembly.github.io/spec/js-api/#webassembly-namespace)
+    #[cfg(feature = "webassembly")]
+    webassembly: JsObject,
+
     /// [`%CSS%`](https://drafts.csswg.org/css-typed-om-1/#css-namespace)
     css: JsObject,
 }
@@ -2283,6 +2292,8 @@ impl IntrinsicObjects {
             temporal: JsObject::default(),
             #[cfg(feature = "temporal")]
             now: JsObject::default(),
+            #[cfg(feature = "webassembly")]
+            webassembly: JsObject::default(),
             css: JsObject::default(),
         })
     }
@@ -2398,6 +2409,16 @@ impl IntrinsicObjects {
         self.json.clone()
     }
 
+    /// Gets the [`%WebAssembly%`][spec] intrinsic object.
+    ///
+    /// [spec]: https://webassembly.github.io/spec/js-api/#webassembly-namespace
+    #[cfg(feature = "webassembly")]
+    #[inline]
+    #[must_use]
+    pub fn webassembly(&self) -> JsObject {
+        self.webassembly.clone()
+    }
+
     /// Gets the [`%CSS%`][spec] intrinsic object.
     ///
     /// [spec]: https://drafts.csswg.org/css-typed-om-1/#css-namespace
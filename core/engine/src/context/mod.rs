@@ -18,7 +18,7 @@ use crate::vm::RuntimeLimits;
 use crate::{
     HostDefined, JsNativeError, JsResult, JsString, JsValue, NativeObject, Source, builtins,
     class::{Class, ClassBuilder},
-    job::{JobExecutor, SimpleJobExecutor},
+    job::{JobExecutor, JobStatistics, SimpleJobExecutor},
     js_string,
     module::{IdleModuleLoader, ModuleLoader, SimpleModuleLoader},
     native_function::NativeFunction,
@@ -26,7 +26,7 @@ use crate::{
     optimizer::{Optimizer, OptimizerOptions, OptimizerStatistics},
     property::{Attribute, PropertyDescriptor, PropertyKey},
     realm::Realm,
-    script::Script,
+    script::{Script, ScriptCache},
     vm::{ActiveRunnable, CallFrame, Vm},
 };
 
@@ -106,6 +106,9 @@ pub struct Context {
 
     can_block: bool,
 
+    /// The user's preferred locale, as a BCP 47 language tag.
+    locale: Option<JsString>,
+
     #[cfg(feature = "temporal")]
     tz_provider: FsTzdbProvider,
 
@@ -121,6 +124,8 @@ pub struct Context {
 
     module_loader: Rc<dyn DynModuleLoader>,
 
+    pub(crate) script_cache: Option<Rc<ScriptCache>>,
+
     optimizer_options: OptimizerOptions,
     root_shape: RootShape,
 
@@ -479,6 +484,42 @@ impl Context {
         self.job_executor().run_jobs(self)
     }
 
+    /// Runs the jobs that are ready to run with the provided job executor, without waiting
+    /// on pending timers or futures.
+    ///
+    /// See [`JobExecutor::run_jobs_until_stalled`] for more information.
+    #[inline]
+    pub fn run_jobs_until_stalled(&mut self) -> JsResult<()> {
+        self.job_executor().run_jobs_until_stalled(self)
+    }
+
+    /// Returns `true` if the job executor has jobs left to run.
+    ///
+    /// See [`JobExecutor::has_pending_jobs`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn has_pending_jobs(&self) -> bool {
+        self.job_executor.has_pending_jobs()
+    }
+
+    /// Returns `true` if the job executor has host tasks left to run.
+    ///
+    /// See [`JobExecutor::has_pending_host_tasks`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn has_pending_host_tasks(&self) -> bool {
+        self.job_executor.has_pending_host_tasks()
+    }
+
+    /// Returns statistics about the tasks of each priority lane of the job executor.
+    ///
+    /// See [`JobExecutor::statistics`] for more information.
+    #[inline]
+    #[must_use]
+    pub fn job_statistics(&self) -> Option<JobStatistics> {
+        self.job_executor.statistics()
+    }
+
     /// Abstract operation [`ClearKeptObjects`][clear].
     ///
     /// Clears all objects maintained alive by calls to the [`AddToKeptObjects`][add] abstract
@@ -522,6 +563,7 @@ impl Context {
     /// Create a new Realm with the default global bindings.
     pub fn create_realm(&mut self) -> JsResult<Realm> {
         let realm = Realm::create(self.host_hooks.as_ref(), &self.root_shape)?;
+        realm.set_time_origin(self.clock.now());
 
         let old_realm = self.enter_realm(realm);
 
@@ -549,6 +591,25 @@ impl Context {
         self.clock.as_ref()
     }
 
+    /// Gets the script cache used by [`Script::parse_cached`], if any.
+    #[inline]
+    #[must_use]
+    pub fn script_cache(&self) -> Option<&ScriptCache> {
+        self.script_cache.as_deref()
+    }
+
+    /// Returns the [current high resolution time][spec] of the current realm, which is the
+    /// number of milliseconds elapsed since the [time origin][Realm::time_origin] of the realm,
+    /// as measured by the context's clock.
+    ///
+    /// [spec]: https://w3c.github.io/hr-time/#dfn-current-high-resolution-time
+    #[must_use]
+    pub fn current_high_resolution_time(&self) -> f64 {
+        let origin = self.realm().time_origin().nanos_since_epoch();
+        let now = self.clock.now().nanos_since_epoch();
+        now.saturating_sub(origin) as f64 / 1_000_000.0
+    }
+
     /// Gets the current job executor, or `None` if the current job executor
     /// is not a `T`.
     #[inline]
@@ -590,6 +651,44 @@ impl Context {
         self.can_block
     }
 
+    /// Gets the user's preferred locale, as a BCP 47 language tag.
+    ///
+    /// Returns `None` if the locale of the system should be used instead.
+    #[inline]
+    #[must_use]
+    pub const fn locale(&self) -> Option<&JsString> {
+        self.locale.as_ref()
+    }
+
+    /// Sets the user's preferred locale, as a BCP 47 language tag.
+    ///
+    /// The locale is used as the default locale of `Intl`, and hosts can expose it through APIs
+    /// such as `navigator.language`. The tag is stored in its canonical form, e.g. `"EN-us"`
+    /// is stored as `"en-US"`. Passing `None` reverts to the locale of the system.
+    ///
+    /// # Errors
+    /// Returns a `RangeError` if the tag is not a structurally valid language tag.
+    pub fn set_locale(&mut self, locale: Option<JsString>) -> JsResult<()> {
+        let Some(tag) = locale else {
+            #[cfg(feature = "intl")]
+            self.intl_provider.set_default_locale(None);
+            self.locale = None;
+            return Ok(());
+        };
+
+        #[cfg(feature = "intl")]
+        {
+            let locale = builtins::intl::locale::locale_from_value(&tag.into(), self)?;
+            self.locale = Some(JsString::from(locale.to_string()));
+            self.intl_provider.set_default_locale(Some(locale));
+        }
+        #[cfg(not(feature = "intl"))]
+        {
+            self.locale = Some(canonicalize_language_tag(&tag)?);
+        }
+        Ok(())
+    }
+
     /// Insert a type into the context-specific [`HostDefined`] field.
     #[inline]
     pub fn insert_data<T: NativeObject>(&mut self, value: T) -> Option<Box<T>> {
@@ -899,7 +998,9 @@ pub struct ContextBuilder {
     clock: Option<Rc<dyn Clock>>,
     job_executor: Option<Rc<dyn JobExecutor>>,
     module_loader: Option<Rc<dyn DynModuleLoader>>,
+    script_cache: Option<Rc<ScriptCache>>,
     can_block: bool,
+    locale: Option<JsString>,
     #[cfg(feature = "intl")]
     icu: Option<icu::IntlProvider>,
     #[cfg(feature = "fuzz")]
@@ -930,7 +1031,9 @@ impl std::fmt::Debug for ContextBuilder {
                 "module_loader",
                 &self.module_loader.as_ref().map(|_| ModuleLoader),
             )
-            .field("can_block", &self.can_block);
+            .field("script_cache", &self.script_cache)
+            .field("can_block", &self.can_block)
+            .field("locale", &self.locale);
 
         #[cfg(feature = "intl")]
         out.field("icu", &self.icu);
@@ -1025,6 +1128,15 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the [`ScriptCache`] used by [`Script::parse_cached`] for the context.
+    ///
+    /// Scripts are not cached by default.
+    #[must_use]
+    pub fn script_cache(mut self, script_cache: Rc<ScriptCache>) -> Self {
+        self.script_cache = Some(script_cache);
+        self
+    }
+
     /// [`AgentCanSuspend ( )`][spec] aka `[[CanBlock]]`
     ///
     /// Defines if this context can be suspended by calls to the [`Atomics.wait`][wait] function.
@@ -1043,6 +1155,16 @@ impl ContextBuilder {
         self
     }
 
+    /// Initializes the user's preferred locale, as a BCP 47 language tag.
+    ///
+    /// See [`Context::set_locale`] for more information. Building the context fails if the
+    /// tag is not a structurally valid language tag.
+    #[must_use]
+    pub fn locale(mut self, locale: JsString) -> Self {
+        self.locale = Some(locale);
+        self
+    }
+
     /// Specifies the number of instructions remaining to the [`Context`].
     ///
     /// This function is only available if the `fuzz` feature is enabled.
@@ -1075,6 +1197,7 @@ impl ContextBuilder {
         let host_hooks = self.host_hooks.unwrap_or(Rc::new(DefaultHooks));
         let clock = self.clock.unwrap_or_else(|| Rc::new(StdClock));
         let realm = Realm::create(host_hooks.as_ref(), &root_shape)?;
+        realm.set_time_origin(clock.now());
         let vm = Vm::new(realm);
 
         let module_loader: Rc<dyn DynModuleLoader> = if let Some(loader) = self.module_loader {
@@ -1117,19 +1240,67 @@ impl ContextBuilder {
             clock,
             job_executor,
             module_loader,
+            script_cache: self.script_cache,
             optimizer_options: OptimizerOptions::OPTIMIZE_ALL,
             root_shape,
             parser_identifier: 0,
             can_block: self.can_block,
+            locale: None,
             data: HostDefined::default(),
         };
 
+        context.set_locale(self.locale)?;
+
         builtins::set_default_global_bindings(&mut context)?;
 
         Ok(context)
     }
 }
 
+/// Checks that `tag` is a structurally valid BCP 47 language tag, returning it with the
+/// canonical case of its subtags.
+///
+/// Without `Intl`, the ICU data needed to fully canonicalize a tag isn't available, so
+/// only the case of the subtags is normalized.
+#[cfg(not(feature = "intl"))]
+fn canonicalize_language_tag(tag: &JsString) -> JsResult<JsString> {
+    let invalid =
+        || JsNativeError::range().with_message("locale is not a structurally valid language tag");
+    let tag = tag.to_std_string().map_err(|_| invalid())?;
+    let alphabetic = |subtag: &str| subtag.bytes().all(|b| b.is_ascii_alphabetic());
+
+    let mut subtags = tag.split('-');
+    let language = subtags.next().unwrap_or_default();
+    if !matches!(language.len(), 2..=3 | 5..=8) || !alphabetic(language) {
+        return Err(invalid().into());
+    }
+
+    let mut canonical = language.to_ascii_lowercase();
+    let mut extension = false;
+    for (index, subtag) in subtags.enumerate() {
+        if !(1..=8).contains(&subtag.len()) || !subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+        {
+            return Err(invalid().into());
+        }
+        // Subtags following a singleton belong to an extension, which is lowercase.
+        extension |= subtag.len() == 1;
+
+        canonical.push('-');
+        if !extension && index == 0 && subtag.len() == 4 && alphabetic(subtag) {
+            // Script subtags are titlecase.
+            canonical.push_str(&subtag[..1].to_ascii_uppercase());
+            canonical.push_str(&subtag[1..].to_ascii_lowercase());
+        } else if !extension && index <= 1 && subtag.len() == 2 && alphabetic(subtag) {
+            // Region subtags are uppercase.
+            canonical.push_str(&subtag.to_ascii_uppercase());
+        } else {
+            canonical.push_str(&subtag.to_ascii_lowercase());
+        }
+    }
+
+    Ok(JsString::from(canonical))
+}
+
 /// A cleanup guard for a [`Context`] that is executed when dropped.
 #[derive(Debug)]
 pub(crate) struct ContextCleanupGuard<'a, F>
@@ -211,3 +211,26 @@ fn basic() {
     assert_eq!(now4.millis_since_epoch(), u64::MAX);
     assert!(now4 > now3);
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn high_resolution_time() {
+    use std::rc::Rc;
+
+    let clock = Rc::new(FixedClock::from_millis(5000));
+    let context = crate::Context::builder()
+        .clock(clock.clone())
+        .build()
+        .unwrap();
+
+    // Realms start at the current time of the context clock.
+    assert_eq!(context.realm().time_origin().millis_since_epoch(), 5000);
+    assert_eq!(context.current_high_resolution_time(), 0.0);
+
+    clock.forward(1500);
+    assert_eq!(context.current_high_resolution_time(), 1500.0);
+
+    // Embedders can move the origin of a realm.
+    context.realm().set_time_origin(JsInstant::new(6, 0));
+    assert_eq!(context.current_high_resolution_time(), 500.0);
+}
@@ -7,6 +7,9 @@
 //! - [`NativeAsyncJob`] for jobs that support [`Future`].
 //! - [`NativeJob`] for generic jobs that aren't related to Promises.
 //!
+//! Generic and timeout jobs are tasks with a [`TaskPriority`], which executors supporting
+//! priorities use to run them in priority lanes, reporting per-lane [`JobStatistics`].
+//!
 //! [`JobCallback`] is an ECMAScript [`JobCallback`] record, containing an `ECMAScript` function
 //! that is executed when a promise is either fulfilled or rejected.
 //!
@@ -45,6 +48,7 @@ use std::cell::Cell;
 use std::collections::BTreeMap;
 use std::mem;
 use std::rc::Rc;
+use std::task::{Poll, Waker};
 use std::{cell::RefCell, collections::VecDeque, fmt::Debug, future::Future, pin::Pin};
 
 /// An ECMAScript [Job Abstract Closure].
@@ -159,6 +163,8 @@ pub struct TimeoutJob {
     job: NativeJob,
     /// Signals if the timeout job was cancelled.
     cancelled: OnceFlag,
+    /// The priority lane of the job.
+    priority: TaskPriority,
 }
 
 impl TimeoutJob {
@@ -169,9 +175,24 @@ impl TimeoutJob {
             timeout: JsDuration::from_millis(timeout_in_millis),
             job,
             cancelled: OnceFlag::new(),
+            priority: TaskPriority::default(),
         }
     }
 
+    /// Sets the priority lane of this job.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority lane of this job.
+    #[inline]
+    #[must_use]
+    pub const fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+
     /// Creates a new `TimeoutJob` from a closure and a timeout as [`std::time::Duration`].
     #[must_use]
     pub fn from_duration<F>(f: F, timeout: impl Into<JsDuration>) -> Self
@@ -227,7 +248,10 @@ impl TimeoutJob {
 /// to priority and ordering.
 ///
 /// [HostEnqueueGenericJob]: https://tc39.es/ecma262/#sec-hostenqueuegenericjob
-pub struct GenericJob(NativeJob);
+pub struct GenericJob {
+    job: NativeJob,
+    priority: TaskPriority,
+}
 
 impl Debug for GenericJob {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
@@ -241,13 +265,30 @@ impl GenericJob {
     where
         F: FnOnce(&mut Context) -> JsResult<JsValue> + 'static,
     {
-        Self(NativeJob::with_realm(f, realm))
+        Self {
+            job: NativeJob::with_realm(f, realm),
+            priority: TaskPriority::default(),
+        }
+    }
+
+    /// Sets the priority lane of this job.
+    #[must_use]
+    pub const fn with_priority(mut self, priority: TaskPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Returns the priority lane of this job.
+    #[inline]
+    #[must_use]
+    pub const fn priority(&self) -> TaskPriority {
+        self.priority
     }
 
     /// Gets a reference to the execution realm of the job.
     #[must_use]
     pub const fn realm(&self) -> &Realm {
-        self.0
+        self.job
             .realm
             .as_ref()
             .expect("all generic jobs must have an execution realm")
@@ -256,7 +297,57 @@ impl GenericJob {
     /// Calls the `GenericJob` with the specified [`Context`], setting the execution
     /// context to the job's realm before calling the inner closure, and resets it after execution.
     pub fn call(self, context: &mut Context) -> JsResult<JsValue> {
-        self.0.call(context)
+        self.job.call(context)
+    }
+}
+
+/// The priority of a task, as defined by the [Prioritized Task Scheduling API][spec].
+///
+/// Executors supporting priorities run the ready tasks of higher priority lanes first,
+/// preserving the order of the tasks within each lane.
+///
+/// [spec]: https://wicg.github.io/scheduling-apis/#sec-task-priorities
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TaskPriority {
+    /// Tasks blocking the user from interacting with the page.
+    UserBlocking,
+    /// Tasks visible to the user but not blocking input. This is the default priority.
+    #[default]
+    UserVisible,
+    /// Tasks that are not time-critical.
+    Background,
+}
+
+impl TaskPriority {
+    /// All the priorities, from the highest to the lowest.
+    pub const ALL: [Self; 3] = [Self::UserBlocking, Self::UserVisible, Self::Background];
+
+    /// The index of the lane of this priority.
+    const fn lane(self) -> usize {
+        self as usize
+    }
+}
+
+/// Statistics about the tasks of a priority lane.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LaneStatistics {
+    /// The number of tasks waiting to run, including timeouts that didn't expire yet.
+    pub pending: usize,
+    /// The number of tasks that ran.
+    pub completed: u64,
+}
+
+/// Statistics about the tasks of each priority lane of a [`JobExecutor`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct JobStatistics {
+    lanes: [LaneStatistics; 3],
+}
+
+impl JobStatistics {
+    /// Returns the statistics of the lane of `priority`.
+    #[must_use]
+    pub const fn lane(&self, priority: TaskPriority) -> LaneStatistics {
+        self.lanes[priority.lane()]
     }
 }
 
@@ -553,6 +644,43 @@ pub trait JobExecutor: Any {
     /// Runs all jobs in the executor.
     fn run_jobs(self: Rc<Self>, context: &mut Context) -> JsResult<()>;
 
+    /// Runs the jobs that are ready to run, returning once the only jobs left are waiting
+    /// on a timer or on a [`Future`].
+    ///
+    /// This allows embedders to drive the executor from their own event loop without
+    /// blocking on pending work. Host tasks, i.e. [`NativeAsyncJob`]s, are not polled; see
+    /// [`JobExecutor::has_pending_host_tasks`]. By default forwards to [`JobExecutor::run_jobs`].
+    fn run_jobs_until_stalled(self: Rc<Self>, context: &mut Context) -> JsResult<()> {
+        self.run_jobs(context)
+    }
+
+    /// Returns `true` if the executor has jobs left to run with
+    /// [`JobExecutor::run_jobs_until_stalled`], either ready or waiting on a timer.
+    ///
+    /// Executors that don't track their jobs return `false` by default.
+    fn has_pending_jobs(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the executor has host tasks left to run, i.e. [`NativeAsyncJob`]s
+    /// waiting on a [`Future`], such as a fetch or the consumption of a body.
+    ///
+    /// The futures of host tasks borrow the [`Context`] while they run, so they can only be
+    /// driven by [`JobExecutor::run_jobs`] or [`JobExecutor::run_jobs_async`].
+    ///
+    /// Executors that don't track their jobs return `false` by default.
+    fn has_pending_host_tasks(&self) -> bool {
+        false
+    }
+
+    /// Returns statistics about the tasks of each priority lane, allowing embedders to
+    /// interleave the work of the engine with their own.
+    ///
+    /// Executors that don't track their jobs return `None` by default.
+    fn statistics(&self) -> Option<JobStatistics> {
+        None
+    }
+
     /// Asynchronously runs all jobs in the executor.
     ///
     /// By default forwards to [`JobExecutor::run_jobs`]. Implementors using async should override this
@@ -600,6 +728,13 @@ impl JobExecutor for IdleJobExecutor {
 
 /// A simple FIFO executor that bails on the first error.
 ///
+/// Generic and timeout jobs run in priority lanes: the ready jobs of a higher
+/// [`TaskPriority`] run first, in FIFO order within each lane.
+///
+/// While only host tasks are left, [`JobExecutor::run_jobs_async`] waits on the waker of
+/// the executor polling it, so the completion of background IO, or a newly enqueued job,
+/// wakes the embedder instead of it polling in a loop.
+///
 /// This is the default job executor for the [`Context`], but it is mostly pretty limited
 /// for a custom event loop.
 ///
@@ -610,7 +745,9 @@ pub struct SimpleJobExecutor {
     promise_jobs: RefCell<VecDeque<PromiseJob>>,
     async_jobs: RefCell<VecDeque<NativeAsyncJob>>,
     timeout_jobs: RefCell<BTreeMap<JsInstant, TimeoutJob>>,
-    generic_jobs: RefCell<VecDeque<GenericJob>>,
+    generic_jobs: RefCell<[VecDeque<GenericJob>; 3]>,
+    completed: [Cell<u64>; 3],
+    waker: RefCell<Option<Waker>>,
 }
 
 impl SimpleJobExecutor {
@@ -618,7 +755,46 @@ impl SimpleJobExecutor {
         self.promise_jobs.borrow_mut().clear();
         self.async_jobs.borrow_mut().clear();
         self.timeout_jobs.borrow_mut().clear();
-        self.generic_jobs.borrow_mut().clear();
+        self.generic_jobs
+            .borrow_mut()
+            .iter_mut()
+            .for_each(VecDeque::clear);
+    }
+
+    /// Removes the timeout jobs that expired at `now`, dropping the cancelled ones that
+    /// are still pending. The jobs are ordered by priority, then by deadline.
+    fn take_expired_timeouts(&self, now: JsInstant) -> Vec<TimeoutJob> {
+        let mut timeouts_borrow = self.timeout_jobs.borrow_mut();
+        let mut jobs_to_keep = timeouts_borrow.split_off(&now);
+        jobs_to_keep.retain(|_, job| !job.is_cancelled());
+        let mut expired = mem::replace(&mut *timeouts_borrow, jobs_to_keep)
+            .into_values()
+            .collect::<Vec<_>>();
+        expired.sort_by_key(TimeoutJob::priority);
+        expired
+    }
+
+    /// Removes all the generic jobs, ordered by priority, then by enqueue order.
+    fn take_generic_jobs(&self) -> Vec<GenericJob> {
+        self.generic_jobs
+            .borrow_mut()
+            .iter_mut()
+            .flat_map(mem::take)
+            .collect()
+    }
+
+    /// Returns `true` if a job can run without waiting on a host task.
+    fn has_ready_jobs(&self) -> bool {
+        !self.promise_jobs.borrow().is_empty()
+            || !self.async_jobs.borrow().is_empty()
+            || !self.generic_jobs.borrow().iter().all(VecDeque::is_empty)
+            || !self.timeout_jobs.borrow().is_empty()
+    }
+
+    /// Records that a task of the lane of `priority` ran.
+    fn record_completed(&self, priority: TaskPriority) {
+        let completed = &self.completed[priority.lane()];
+        completed.set(completed.get() + 1);
     }
 }
 
@@ -645,7 +821,12 @@ impl JobExecutor for SimpleJobExecutor {
                 let now = context.clock().now();
                 self.timeout_jobs.borrow_mut().insert(now + t.timeout(), t);
             }
-            Job::GenericJob(g) => self.generic_jobs.borrow_mut().push_back(g),
+            Job::GenericJob(g) => self.generic_jobs.borrow_mut()[g.priority().lane()].push_back(g),
+        }
+
+        // Wake `run_jobs_async` if it is waiting on host tasks.
+        if let Some(waker) = self.waker.borrow_mut().take() {
+            waker.wake();
         }
     }
 
@@ -665,31 +846,44 @@ impl JobExecutor for SimpleJobExecutor {
 
             if self.promise_jobs.borrow().is_empty()
                 && self.async_jobs.borrow().is_empty()
-                && self.generic_jobs.borrow().is_empty()
+                && self.generic_jobs.borrow().iter().all(VecDeque::is_empty)
                 && self.timeout_jobs.borrow().is_empty()
                 && group.is_empty()
             {
                 break;
             }
 
-            if let Some(Err(err)) = future::poll_once(group.next()).await.flatten() {
+            let result = if self.has_ready_jobs() {
+                future::poll_once(group.next()).await.flatten()
+            } else {
+                // Only host tasks are left: wait until one of them completes, or until a
+                // job is enqueued, instead of polling them in a loop. This lets background
+                // IO wake the executor that drives this future.
+                future::poll_fn(|cx| {
+                    if self.has_ready_jobs() {
+                        return Poll::Ready(None);
+                    }
+                    self.waker.borrow_mut().replace(cx.waker().clone());
+                    group.poll_next(cx)
+                })
+                .await
+            };
+            if let Some(Err(err)) = result {
                 self.clear();
                 return Err(err);
             }
 
             {
                 let now = context.borrow().clock().now();
-                let mut timeouts_borrow = self.timeout_jobs.borrow_mut();
-                let mut jobs_to_keep = timeouts_borrow.split_off(&now);
-                jobs_to_keep.retain(|_, job| !job.is_cancelled());
-                let jobs_to_run = mem::replace(&mut *timeouts_borrow, jobs_to_keep);
-                drop(timeouts_borrow);
+                let jobs_to_run = self.take_expired_timeouts(now);
 
-                for job in jobs_to_run.into_values() {
+                for job in jobs_to_run {
+                    let priority = job.priority();
                     if let Err(err) = job.call(&mut context.borrow_mut()) {
                         self.clear();
                         return Err(err);
                     }
+                    self.record_completed(priority);
                 }
             }
 
@@ -701,12 +895,13 @@ impl JobExecutor for SimpleJobExecutor {
                 }
             }
 
-            let jobs = mem::take(&mut *self.generic_jobs.borrow_mut());
-            for job in jobs {
+            for job in self.take_generic_jobs() {
+                let priority = job.priority();
                 if let Err(err) = job.call(&mut context.borrow_mut()) {
                     self.clear();
                     return Err(err);
                 }
+                self.record_completed(priority);
             }
             context.borrow_mut().clear_kept_objects();
             future::yield_now().await;
@@ -714,4 +909,77 @@ impl JobExecutor for SimpleJobExecutor {
 
         Ok(())
     }
+
+    fn run_jobs_until_stalled(self: Rc<Self>, context: &mut Context) -> JsResult<()> {
+        loop {
+            let mut ran_jobs = false;
+
+            let now = context.clock().now();
+            for job in self.take_expired_timeouts(now) {
+                ran_jobs = true;
+                let priority = job.priority();
+                if let Err(err) = job.call(context) {
+                    self.clear();
+                    return Err(err);
+                }
+                self.record_completed(priority);
+            }
+
+            let jobs = mem::take(&mut *self.promise_jobs.borrow_mut());
+            for job in jobs {
+                ran_jobs = true;
+                if let Err(err) = job.call(context) {
+                    self.clear();
+                    return Err(err);
+                }
+            }
+
+            for job in self.take_generic_jobs() {
+                ran_jobs = true;
+                let priority = job.priority();
+                if let Err(err) = job.call(context) {
+                    self.clear();
+                    return Err(err);
+                }
+                self.record_completed(priority);
+            }
+
+            if !ran_jobs {
+                break;
+            }
+            context.clear_kept_objects();
+        }
+
+        Ok(())
+    }
+
+    fn has_pending_jobs(&self) -> bool {
+        !self.promise_jobs.borrow().is_empty()
+            || !self.generic_jobs.borrow().iter().all(VecDeque::is_empty)
+            || self
+                .timeout_jobs
+                .borrow()
+                .values()
+                .any(|job| !job.is_cancelled())
+    }
+
+    fn has_pending_host_tasks(&self) -> bool {
+        !self.async_jobs.borrow().is_empty()
+    }
+
+    fn statistics(&self) -> Option<JobStatistics> {
+        let mut statistics = JobStatistics::default();
+        for (lane, jobs) in self.generic_jobs.borrow().iter().enumerate() {
+            statistics.lanes[lane].pending = jobs.len();
+        }
+        for job in self.timeout_jobs.borrow().values() {
+            if !job.is_cancelled() {
+                statistics.lanes[job.priority().lane()].pending += 1;
+            }
+        }
+        for (lane, completed) in self.completed.iter().enumerate() {
+            statistics.lanes[lane].completed = completed.get();
+        }
+        Some(statistics)
+    }
 }
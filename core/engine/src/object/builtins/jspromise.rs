@@ -8,7 +8,7 @@ use crate::{
         Promise,
         promise::{PromiseState, ResolvingFunctions},
     },
-    job::NativeAsyncJob,
+    job::{NativeAsyncJob, PromiseJob},
     object::JsObject,
     value::TryFromJs,
 };
@@ -354,6 +354,54 @@ impl JsPromise {
         }
     }
 
+    /// Creates a new pending `JsPromise` that gets settled with the `Result<T, JsError>` on a
+    /// new microtask, instead of synchronously like [`JsPromise::from_result`].
+    ///
+    /// Platform APIs that complete their work synchronously should use this to settle their
+    /// promises, so that scripts observe the settlement after the current task like they
+    /// would with a truly asynchronous operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use boa_engine::{
+    /// #    object::builtins::JsPromise,
+    /// #    builtins::promise::PromiseState,
+    /// #    Context, JsError, js_string
+    /// # };
+    /// let context = &mut Context::default();
+    ///
+    /// let promise =
+    ///     JsPromise::from_result_deferred(Ok::<_, JsError>(js_string!("resolved!")), context);
+    /// assert_eq!(promise.state(), PromiseState::Pending);
+    ///
+    /// context.run_jobs().unwrap();
+    /// assert_eq!(
+    ///     promise.state(),
+    ///     PromiseState::Fulfilled(js_string!("resolved!").into())
+    /// );
+    /// ```
+    pub fn from_result_deferred<V: Into<JsValue>, E: Into<JsError>>(
+        value: Result<V, E>,
+        context: &mut Context,
+    ) -> Self {
+        let (promise, resolvers) = Self::new_pending(context);
+        let value: JsResult<JsValue> = value.map(Into::into).map_err(Into::into);
+
+        context.enqueue_job(
+            PromiseJob::new(move |context| match value {
+                Ok(v) => resolvers.resolve.call(&JsValue::undefined(), &[v], context),
+                Err(e) => {
+                    let e = e.to_opaque(context);
+                    resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                }
+            })
+            .into(),
+        );
+
+        promise
+    }
+
     /// Resolves a `JsValue` into a `JsPromise`.
     ///
     /// Equivalent to the [`Promise.resolve()`] static method.
@@ -6,7 +6,7 @@
 //!
 //! A realm is represented in this implementation as a Realm struct with the fields specified from the spec.
 
-use std::any::TypeId;
+use std::{any::TypeId, cell::Cell};
 
 use crate::{
     Context, HostDefined, JsNativeError, JsObject, JsResult, JsString,
@@ -14,6 +14,7 @@ use crate::{
     context::{
         HostHooks,
         intrinsics::{Intrinsics, StandardConstructor},
+        time::JsInstant,
     },
     environments::DeclarativeEnvironment,
     module::Module,
@@ -75,6 +76,11 @@ struct Inner {
     external_constructors: GcRefCell<FxHashMap<&'static str, StandardConstructor>>,
 
     host_defined: GcRefCell<HostDefined>,
+
+    /// The instant from which the high resolution timestamps of this realm are measured.
+    // Safety: Nothing in `JsInstant` needs tracing.
+    #[unsafe_ignore_trace]
+    time_origin: Cell<JsInstant>,
 }
 
 impl Realm {
@@ -104,6 +110,7 @@ impl Realm {
                 host_classes: GcRefCell::default(),
                 external_constructors: GcRefCell::default(),
                 host_defined: GcRefCell::default(),
+                time_origin: Cell::new(JsInstant::new(0, 0)),
             }),
         };
 
@@ -166,6 +173,29 @@ impl Realm {
         self.inner.host_defined.borrow_mut()
     }
 
+    /// Gets the [time origin][spec] of this `Realm`.
+    ///
+    /// High resolution timestamps, like the ones returned by `performance.now()` or stored
+    /// in `Event.timeStamp`, are measured from this instant.
+    ///
+    /// [spec]: https://w3c.github.io/hr-time/#dfn-time-origin
+    #[inline]
+    #[must_use]
+    pub fn time_origin(&self) -> JsInstant {
+        self.inner.time_origin.get()
+    }
+
+    /// Sets the [time origin][spec] of this `Realm`.
+    ///
+    /// Realms created by a [`Context`] start at the current time of the context's clock, but
+    /// embedders can move the origin, e.g. to share a single origin between related realms.
+    ///
+    /// [spec]: https://w3c.github.io/hr-time/#dfn-time-origin
+    #[inline]
+    pub fn set_time_origin(&self, time_origin: JsInstant) {
+        self.inner.time_origin.set(time_origin);
+    }
+
     /// Checks if this `Realm` has the class `C` registered into its class map.
     #[must_use]
     pub fn has_class<C: Class>(&self) -> bool {
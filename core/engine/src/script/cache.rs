@@ -0,0 +1,204 @@
+//! A cache of parsed scripts, keyed by their source text.
+
+use std::cell::RefCell;
+
+use rustc_hash::FxHashMap;
+
+use crate::{realm::Realm, spanned_source_text::SourceText};
+
+/// A size-bounded cache of parsed scripts, keyed by their source text.
+///
+/// Embedders evaluating the same source text repeatedly can opt into caching by passing a
+/// `ScriptCache` to [`ContextBuilder::script_cache`][crate::context::ContextBuilder::script_cache],
+/// then parsing with [`Script::parse_cached`][super::Script::parse_cached], which skips
+/// parsing on cache hits.
+///
+/// Only the syntax tree of a script is cached. Its scopes are analyzed and its bytecode is
+/// compiled again for every script returned from the cache, as both depend on the global
+/// declarations that exist at that time.
+///
+/// A cached script is only reused for the same realm and strictness it was parsed with.
+/// When the cache is full, the least recently used script is evicted.
+#[derive(Debug)]
+pub struct ScriptCache {
+    capacity: usize,
+    inner: RefCell<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// The cached scripts, grouped by source text.
+    entries: FxHashMap<Box<[u8]>, Vec<Entry>>,
+    /// The number of cached scripts.
+    len: usize,
+    /// A counter incremented on each access, used to find the least recently used script.
+    tick: u64,
+}
+
+struct Entry {
+    realm: Realm,
+    /// The syntax tree of the script, before the analysis of its scopes.
+    code: boa_ast::Script,
+    source_text: SourceText,
+    strict: bool,
+    last_used: u64,
+}
+
+impl std::fmt::Debug for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Entry")
+            .field("realm", &self.realm.addr())
+            .field("code", &self.code)
+            .field("strict", &self.strict)
+            .field("last_used", &self.last_used)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Entry {
+    fn matches(&self, realm: &Realm, strict: bool) -> bool {
+        &self.realm == realm && self.strict == strict
+    }
+}
+
+impl ScriptCache {
+    /// Creates a new cache holding at most `capacity` scripts.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: RefCell::default(),
+        }
+    }
+
+    /// Returns the maximum number of scripts held by this cache.
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of scripts currently held by this cache.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.inner.borrow().len
+    }
+
+    /// Returns `true` if this cache holds no scripts.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes all the scripts parsed from `code`, returning `true` if any was removed.
+    pub fn invalidate(&self, code: &[u8]) -> bool {
+        let mut inner = self.inner.borrow_mut();
+        let Some(entries) = inner.entries.remove(code) else {
+            return false;
+        };
+        inner.len -= entries.len();
+        true
+    }
+
+    /// Removes all the scripts parsed for `realm`, e.g. before the realm is discarded.
+    pub fn invalidate_realm(&self, realm: &Realm) {
+        let mut inner = self.inner.borrow_mut();
+        let mut removed = 0;
+        inner.entries.retain(|_, entries| {
+            let len = entries.len();
+            entries.retain(|entry| &entry.realm != realm);
+            removed += len - entries.len();
+            !entries.is_empty()
+        });
+        inner.len -= removed;
+    }
+
+    /// Removes all the scripts of this cache.
+    pub fn clear(&self) {
+        let mut inner = self.inner.borrow_mut();
+        inner.entries.clear();
+        inner.len = 0;
+    }
+
+    /// Returns the syntax tree and source text of the script parsed from `code` with the
+    /// given parameters, if cached.
+    pub(crate) fn get(
+        &self,
+        code: &[u8],
+        realm: &Realm,
+        strict: bool,
+    ) -> Option<(boa_ast::Script, SourceText)> {
+        let mut inner = self.inner.borrow_mut();
+        inner.tick += 1;
+        let tick = inner.tick;
+
+        let entry = inner
+            .entries
+            .get_mut(code)?
+            .iter_mut()
+            .find(|entry| entry.matches(realm, strict))?;
+        entry.last_used = tick;
+        Some((entry.code.clone(), entry.source_text.clone()))
+    }
+
+    /// Adds the script parsed from `code` with the given parameters to the cache, evicting
+    /// the least recently used script if the cache is full.
+    ///
+    /// `script` must not have had its scopes analyzed.
+    pub(crate) fn insert(
+        &self,
+        code: &[u8],
+        realm: Realm,
+        script: boa_ast::Script,
+        source_text: SourceText,
+        strict: bool,
+    ) {
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut inner = self.inner.borrow_mut();
+        if inner.len >= self.capacity {
+            inner.evict_least_recently_used();
+        }
+
+        inner.tick += 1;
+        let entry = Entry {
+            realm,
+            code: script,
+            source_text,
+            strict,
+            last_used: inner.tick,
+        };
+        inner.entries.entry(code.into()).or_default().push(entry);
+        inner.len += 1;
+    }
+}
+
+impl Inner {
+    fn evict_least_recently_used(&mut self) {
+        let Some((code, index)) = self
+            .entries
+            .iter()
+            .flat_map(|(code, entries)| {
+                entries
+                    .iter()
+                    .enumerate()
+                    .map(move |(index, entry)| (code, index, entry.last_used))
+            })
+            .min_by_key(|(_, _, last_used)| *last_used)
+            .map(|(code, index, _)| (code.clone(), index))
+        else {
+            return;
+        };
+
+        let entries = self
+            .entries
+            .get_mut(&code)
+            .expect("the evicted entry must exist");
+        entries.swap_remove(index);
+        if entries.is_empty() {
+            self.entries.remove(&code);
+        }
+        self.len -= 1;
+    }
+}
@@ -8,6 +8,12 @@
 //! [spec]: https://tc39.es/ecma262/#sec-scripts
 //! [script]: https://tc39.es/ecma262/#sec-script-records
 
+mod cache;
+#[cfg(test)]
+mod tests;
+
+pub use cache::ScriptCache;
+
 use std::path::{Path, PathBuf};
 
 use rustc_hash::FxHashMap;
@@ -16,7 +22,7 @@ use boa_gc::{Finalize, Gc, GcRefCell, Trace};
 use boa_parser::{Parser, Source, source::ReadChar};
 
 use crate::{
-    Context, HostDefined, JsResult, JsString, JsValue, Module, SpannedSourceText,
+    Context, HostDefined, JsNativeError, JsResult, JsString, JsValue, Module, SpannedSourceText,
     bytecompiler::{ByteCompiler, global_declaration_instantiation_context},
     js_string,
     realm::Realm,
@@ -91,16 +97,90 @@ impl Script {
             parser.set_strict();
         }
         let scope = context.realm().scope().clone();
-        let (mut code, source) = parser.parse_script_with_source(&scope, context.interner_mut())?;
+        let (code, source) = parser.parse_script_with_source(&scope, context.interner_mut())?;
+
+        Ok(Self::from_analyzed(
+            code,
+            SourceText::new(source),
+            realm.unwrap_or_else(|| context.realm().clone()),
+            path,
+            context,
+        ))
+    }
+
+    /// Parses `code` as an ECMAScript script like [`Script::parse`], reusing the syntax tree
+    /// cached in the [`ScriptCache`] of the context, if any.
+    ///
+    /// On a cache miss, the script is parsed, then added to the cache. The scopes of the
+    /// script are analyzed every time, so the declarations of the script are checked
+    /// against the current global environment, e.g. redeclaring a `let` binding still
+    /// throws a `SyntaxError`.
+    ///
+    /// # Errors
+    /// Returns an error if parsing `code` or analyzing its scopes fails.
+    pub fn parse_cached(
+        code: &[u8],
+        realm: Option<Realm>,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let Some(cache) = context.script_cache.clone() else {
+            return Self::parse(Source::from_bytes(code), realm, context);
+        };
+
+        let realm = realm.unwrap_or_else(|| context.realm().clone());
+        let strict = context.is_strict();
+        let (mut script, source_text) = if let Some(cached) = cache.get(code, &realm, strict) {
+            cached
+        } else {
+            let mut parser = Parser::new(Source::from_bytes(code));
+            parser.set_identifier(context.next_parser_identifier());
+            if strict {
+                parser.set_strict();
+            }
+            // An indirect eval is parsed like a script, without analyzing its scopes.
+            let (script, source) = parser.parse_eval(false, context.interner_mut())?;
+            let source_text = SourceText::new(source);
+            cache.insert(
+                code,
+                realm.clone(),
+                script.clone(),
+                source_text.clone(),
+                strict,
+            );
+            (script, source_text)
+        };
+
+        let scope = context.realm().scope().clone();
+        script
+            .analyze_scope(&scope, context.interner())
+            .map_err(|reason| {
+                JsNativeError::syntax().with_message(format!("invalid scope analysis: {reason}"))
+            })?;
+
+        Ok(Self::from_analyzed(
+            script,
+            source_text,
+            realm,
+            None,
+            context,
+        ))
+    }
+
+    /// Creates a script from its syntax tree, after the analysis of its scopes.
+    fn from_analyzed(
+        mut code: boa_ast::Script,
+        source_text: SourceText,
+        realm: Realm,
+        path: Option<PathBuf>,
+        context: &mut Context,
+    ) -> Self {
         if !context.optimizer_options().is_empty() {
             context.optimize_statement_list(code.statements_mut());
         }
 
-        let source_text = SourceText::new(source);
-
-        Ok(Self {
+        Self {
             inner: Gc::new(Inner {
-                realm: realm.unwrap_or_else(|| context.realm().clone()),
+                realm,
                 source: code,
                 source_text,
                 codeblock: GcRefCell::default(),
@@ -108,7 +188,7 @@ impl Script {
                 host_defined: HostDefined::default(),
                 path,
             }),
-        })
+        }
     }
 
     /// Compiles the codeblock of this script.
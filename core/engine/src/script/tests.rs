@@ -0,0 +1,76 @@
+use std::rc::Rc;
+
+use super::{Script, ScriptCache};
+use crate::{Context, JsNativeErrorKind, context::ContextBuilder};
+
+#[test]
+fn parse_cached() {
+    const COUNT: &[u8] = b"globalThis.count = (globalThis.count ?? 0) + 1;";
+
+    let cache = Rc::new(ScriptCache::new(2));
+    let context = &mut ContextBuilder::default()
+        .script_cache(cache.clone())
+        .build()
+        .unwrap();
+
+    let first = Script::parse_cached(COUNT, None, context).unwrap();
+    let second = Script::parse_cached(COUNT, None, context).unwrap();
+    assert_eq!(cache.len(), 1);
+
+    first.evaluate(context).unwrap();
+    assert_eq!(second.evaluate(context).unwrap().as_number(), Some(2.0));
+
+    // Scripts parsed with another strictness are cached separately.
+    context.strict(true);
+    Script::parse_cached(COUNT, None, context).unwrap();
+    assert_eq!(cache.len(), 2);
+    context.strict(false);
+
+    // The least recently used script is evicted when the cache is full.
+    Script::parse_cached(COUNT, None, context).unwrap();
+    Script::parse_cached(b"1 + 1", None, context).unwrap();
+    assert_eq!(cache.len(), 2);
+    context.strict(true);
+    Script::parse_cached(COUNT, None, context).unwrap();
+    assert_eq!(cache.len(), 2);
+    context.strict(false);
+
+    assert!(cache.invalidate(COUNT));
+    assert!(!cache.invalidate(COUNT));
+    assert_eq!(cache.len(), 1);
+
+    cache.invalidate_realm(context.realm());
+    assert!(cache.is_empty());
+}
+
+#[test]
+fn parse_cached_without_cache() {
+    let context = &mut Context::default();
+    assert!(context.script_cache().is_none());
+
+    Script::parse_cached(b"1 + 1", None, context).unwrap();
+    assert!(context.script_cache().is_none());
+}
+
+#[test]
+fn parse_cached_checks_declarations() {
+    const DECLARE: &[u8] = b"let x = 1;";
+
+    let context = &mut ContextBuilder::default()
+        .script_cache(Rc::new(ScriptCache::new(1)))
+        .build()
+        .unwrap();
+
+    Script::parse_cached(DECLARE, None, context)
+        .unwrap()
+        .evaluate(context)
+        .unwrap();
+
+    let error = Script::parse_cached(DECLARE, None, context)
+        .and_then(|script| script.evaluate(context))
+        .unwrap_err();
+    assert_eq!(
+        error.as_native().map(|error| &error.kind),
+        Some(&JsNativeErrorKind::Syntax)
+    );
+}
@@ -38,3 +38,190 @@ fn issue_2658() {
         TestAction::assert_eq("result2.value", 5),
     ]);
 }
+
+#[test]
+fn run_jobs_until_stalled() {
+    use crate::{
+        JsValue,
+        job::{Job, NativeJob, TimeoutJob},
+    };
+
+    run_test_actions([
+        TestAction::run(indoc! {
+            r#"
+                let resolved = false;
+                Promise.resolve().then(() => Promise.resolve()).then(() => { resolved = true; });
+            "#
+        }),
+        TestAction::inspect_context(|ctx| {
+            ctx.enqueue_job(Job::TimeoutJob(TimeoutJob::new(
+                NativeJob::new(|_| Ok(JsValue::undefined())),
+                60_000,
+            )));
+            assert!(ctx.has_pending_jobs());
+
+            ctx.run_jobs_until_stalled().unwrap();
+
+            // The timeout is still waiting for its deadline.
+            assert!(ctx.has_pending_jobs());
+        }),
+        TestAction::assert("resolved"),
+    ]);
+}
+
+#[test]
+fn host_tasks_are_not_pending_jobs() {
+    use crate::{
+        JsValue, Source,
+        job::{Job, NativeAsyncJob},
+    };
+
+    run_test_actions([
+        TestAction::run("let done = false;"),
+        TestAction::inspect_context(|ctx| {
+            ctx.enqueue_job(Job::AsyncJob(NativeAsyncJob::new(async |ctx| {
+                let context = &mut ctx.borrow_mut();
+                context.eval(Source::from_bytes("done = true"))?;
+                Ok(JsValue::undefined())
+            })));
+            assert!(ctx.has_pending_host_tasks());
+            assert!(!ctx.has_pending_jobs());
+
+            // Driving the ready jobs terminates, leaving the host task queued.
+            while ctx.has_pending_jobs() {
+                ctx.run_jobs_until_stalled().unwrap();
+            }
+            ctx.run_jobs_until_stalled().unwrap();
+            assert!(ctx.has_pending_host_tasks());
+
+            ctx.run_jobs().unwrap();
+            assert!(!ctx.has_pending_host_tasks());
+        }),
+        TestAction::assert("done"),
+    ]);
+}
+
+#[test]
+fn host_task_completion_wakes_the_executor() {
+    use crate::{
+        Context, JsValue, Source,
+        context::ContextBuilder,
+        job::{Job, JobExecutor, NativeAsyncJob, SimpleJobExecutor},
+    };
+    use std::{
+        cell::{Cell, RefCell},
+        future::{Future, poll_fn},
+        pin::pin,
+        rc::Rc,
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        task::{self, Poll, Wake, Waker},
+    };
+
+    struct CountingWaker(AtomicUsize);
+
+    impl Wake for CountingWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    // Stands in for background IO: completes once `done` is set, waking its task.
+    let done = Rc::new(Cell::new(false));
+    let io_waker = Rc::new(RefCell::new(None::<Waker>));
+
+    let executor = Rc::new(SimpleJobExecutor::new());
+    let context = &mut ContextBuilder::new()
+        .job_executor(executor.clone())
+        .build()
+        .unwrap();
+    context
+        .eval(Source::from_bytes("var done = false;"))
+        .unwrap();
+    context.enqueue_job(Job::AsyncJob(NativeAsyncJob::new({
+        let done = done.clone();
+        let io_waker = io_waker.clone();
+        async move |ctx| {
+            poll_fn(|cx| {
+                if done.get() {
+                    return Poll::Ready(());
+                }
+                io_waker.borrow_mut().replace(cx.waker().clone());
+                Poll::Pending
+            })
+            .await;
+            ctx.borrow_mut().eval(Source::from_bytes("done = true"))?;
+            Ok(JsValue::undefined())
+        }
+    })));
+
+    let counter = Arc::new(CountingWaker(AtomicUsize::new(0)));
+    let waker = Waker::from(counter.clone());
+    let cx = &mut task::Context::from_waker(&waker);
+    let context: &RefCell<&mut Context> = &RefCell::new(context);
+    let mut run = pin!(executor.run_jobs_async(context));
+
+    // The executor sleeps on the host task instead of waking itself up again.
+    assert!(run.as_mut().poll(cx).is_pending());
+    assert_eq!(counter.0.load(Ordering::SeqCst), 0);
+
+    // Completing the IO wakes the embedder, which can then finish running the jobs.
+    done.set(true);
+    io_waker.borrow_mut().take().unwrap().wake();
+    assert!(counter.0.load(Ordering::SeqCst) > 0);
+    while run.as_mut().poll(cx).is_pending() {}
+
+    let done = context
+        .borrow_mut()
+        .eval(Source::from_bytes("done"))
+        .unwrap();
+    assert_eq!(done, JsValue::from(true));
+}
+
+#[test]
+fn generic_jobs_run_by_priority() {
+    use crate::{
+        Source,
+        job::{GenericJob, Job, TaskPriority},
+        js_string,
+    };
+
+    run_test_actions([
+        TestAction::run("var order = [];"),
+        TestAction::inspect_context(|ctx| {
+            for (name, priority) in [
+                ("background", TaskPriority::Background),
+                ("visible", TaskPriority::UserVisible),
+                ("blocking", TaskPriority::UserBlocking),
+                ("visible2", TaskPriority::UserVisible),
+            ] {
+                let job = GenericJob::new(
+                    move |ctx| ctx.eval(Source::from_bytes(&format!("order.push('{name}')"))),
+                    ctx.realm().clone(),
+                )
+                .with_priority(priority);
+                ctx.enqueue_job(Job::GenericJob(job));
+            }
+
+            let statistics = ctx.job_statistics().unwrap();
+            assert_eq!(statistics.lane(TaskPriority::UserVisible).pending, 2);
+            assert_eq!(statistics.lane(TaskPriority::Background).pending, 1);
+
+            ctx.run_jobs_until_stalled().unwrap();
+
+            let statistics = ctx.job_statistics().unwrap();
+            for priority in TaskPriority::ALL {
+                assert_eq!(statistics.lane(priority).pending, 0);
+            }
+            assert_eq!(statistics.lane(TaskPriority::UserBlocking).completed, 1);
+            assert_eq!(statistics.lane(TaskPriority::UserVisible).completed, 2);
+            assert_eq!(statistics.lane(TaskPriority::Background).completed, 1);
+        }),
+        TestAction::assert_eq(
+            "order.join()",
+            js_string!("blocking,visible,visible2,background"),
+        ),
+    ]);
+}
@@ -43,7 +43,7 @@ use crate::{Context, JsData, JsResult, JsString, JsValue};
 /// assert_eq!(conv5, true);
 /// assert_eq!(conv_nan, false);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Trace, Finalize, JsData)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Trace, Finalize, JsData)]
 pub struct Convert<T: TryFromJs>(pub T);
 
 impl<T: TryFromJs> From<T> for Convert<T> {
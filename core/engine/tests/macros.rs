@@ -37,3 +37,45 @@ fn try_from_js_derive() {
         }
     );
 }
+
+#[test]
+fn try_from_js_derive_dictionary_members() {
+    #[derive(Debug, TryFromJs, Eq, PartialEq)]
+    struct Dictionary {
+        #[boa(required)]
+        name: JsString,
+        #[boa(default)]
+        count: i32,
+        #[boa(default = "default_limit")]
+        limit: i32,
+    }
+
+    fn default_limit() -> i32 {
+        10
+    }
+
+    let mut context = Context::default();
+    let obj = context
+        .eval(Source::from_bytes(
+            br#"({ name: "lock", limit: undefined })"#,
+        ))
+        .unwrap();
+    let result = Dictionary::try_from_js(&obj, &mut context).unwrap();
+    assert_eq!(
+        result,
+        Dictionary {
+            name: js_string!("lock"),
+            count: 0,
+            limit: 10
+        }
+    );
+
+    let obj = context
+        .eval(Source::from_bytes(br#"({ count: 3 })"#))
+        .unwrap();
+    let error = Dictionary::try_from_js(&obj, &mut context).unwrap_err();
+    assert_eq!(
+        error.as_native().unwrap().message(),
+        "required member name is undefined"
+    );
+}
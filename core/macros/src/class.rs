@@ -48,25 +48,33 @@ impl std::fmt::Debug for Function {
 
 impl Function {
     /// Serializes the `self` argument declaration and call.
+    ///
+    /// The downcast doubles as the brand check of the method. Failing it throws a
+    /// `TypeError`, as required by `WebIDL` for operations called on the wrong object.
     fn arg_self_from_receiver(
         receiver: &mut Receiver,
         class_ty: &Type,
+        class_name: &str,
+        fn_name: &str,
     ) -> SpannedResult<(TokenStream2, TokenStream2)> {
-        let err = take_error_from_attrs(&mut receiver.attrs)?
-            .unwrap_or("Invalid class for type".to_string());
+        let err = take_error_from_attrs(&mut receiver.attrs)?.unwrap_or_else(|| {
+            format!(
+                "'{fn_name}' called on an object that does not implement interface {class_name}."
+            )
+        });
 
         // `&mut self`
         let downcast = if receiver.mutability.is_some() {
             quote! {
                 let object = this.as_object();
                 let self_ = &mut *object.as_ref().and_then(|o| o.downcast_mut::< #class_ty >())
-                    .ok_or( boa_engine::js_error!( #err ))?;
+                    .ok_or( boa_engine::js_error!( TypeError: #err ))?;
             }
         } else {
             quote! {
                 let object = this.as_object();
                 let self_ = &*object.as_ref().and_then(|o| o.downcast_ref::< #class_ty >())
-                    .ok_or( boa_engine::js_error!( #err ))?;
+                    .ok_or( boa_engine::js_error!( TypeError: #err ))?;
             }
         };
 
@@ -122,7 +130,7 @@ impl Function {
         has_explicit_static: bool,
         attrs: &mut Vec<Attribute>,
         sig: &mut Signature,
-        class_ty: Option<&Type>,
+        class: Option<(&Type, &str)>,
     ) -> SpannedResult<Self> {
         // The amount of arguments that aren't really arguments in JavaScript,
         // e.g. `self`, `&mut Context`, etc.
@@ -134,8 +142,8 @@ impl Function {
             .map(|(i, a)| match a {
                 FnArg::Receiver(receiver) => {
                     not_param_count += 1;
-                    if let Some(cty) = class_ty {
-                        Self::arg_self_from_receiver(receiver, cty)
+                    if let Some((cty, cname)) = class {
+                        Self::arg_self_from_receiver(receiver, cty, cname, &name)
                     } else {
                         error(receiver, "Invalid context for using a receiver.")
                     }
@@ -179,7 +187,7 @@ impl Function {
         let is_static = has_explicit_static || !(has_explicit_method || not_param_count > 0);
 
         // If this is a scoped function to a type (e.g. inside an `impl` block),
-        let scope = if class_ty.is_some() {
+        let scope = if class.is_some() {
             quote! { Self :: }
         } else {
             quote! {}
@@ -210,7 +218,7 @@ impl Function {
         has_explicit_method: bool,
         has_explicit_static: bool,
         fn_: &mut ImplItemFn,
-        class_ty: Option<&Type>,
+        class: Option<(&Type, &str)>,
     ) -> SpannedResult<Self> {
         if fn_.sig.asyncness.is_some() {
             error(&fn_.sig.asyncness, "Async methods are not supported.")?;
@@ -226,16 +234,16 @@ impl Function {
             has_explicit_static,
             &mut fn_.attrs,
             &mut fn_.sig,
-            class_ty,
+            class,
         )
     }
 
-    fn getter(name: String, fn_: &mut ImplItemFn, class_ty: &Type) -> SpannedResult<Self> {
-        Self::method(name, false, true, fn_, Some(class_ty))
+    fn getter(name: String, fn_: &mut ImplItemFn, class: (&Type, &str)) -> SpannedResult<Self> {
+        Self::method(name, false, true, fn_, Some(class))
     }
 
-    fn setter(name: String, fn_: &mut ImplItemFn, class_ty: &Type) -> SpannedResult<Self> {
-        Self::method(name, false, true, fn_, Some(class_ty))
+    fn setter(name: String, fn_: &mut ImplItemFn, class: (&Type, &str)) -> SpannedResult<Self> {
+        Self::method(name, false, true, fn_, Some(class))
     }
 
     fn constructor(fn_: &mut ImplItemFn, _class_ty: &Type) -> SpannedResult<Self> {
@@ -319,12 +327,12 @@ impl Accessor {
         &mut self,
         name: String,
         fn_: &mut ImplItemFn,
-        class_ty: &Type,
+        class: (&Type, &str),
     ) -> SpannedResult<()> {
         if self.getter.is_some() {
             error(fn_, "Getter for property {name:?} already declared.")
         } else {
-            let getter = Function::getter(name, fn_, class_ty)?;
+            let getter = Function::getter(name, fn_, class)?;
             self.getter = Some(getter);
             Ok(())
         }
@@ -334,7 +342,7 @@ impl Accessor {
         &mut self,
         name: String,
         fn_: &mut ImplItemFn,
-        class_ty: &Type,
+        class: (&Type, &str),
     ) -> SpannedResult<()> {
         if self.setter.is_some() {
             error(
@@ -342,7 +350,7 @@ impl Accessor {
                 format!("Setter for property {name:?} already declared."),
             )
         } else {
-            let setter = Function::setter(name, fn_, class_ty)?;
+            let setter = Function::setter(name, fn_, class)?;
             self.setter = Some(setter);
             Ok(())
         }
@@ -404,6 +412,9 @@ struct ClassVisitor {
     // The type name for this class.
     type_: Type,
 
+    // The JavaScript name of this class, used for brand check errors.
+    name: String,
+
     // Whether we detected a constructor while visiting.
     constructor: Option<Function>,
 
@@ -421,10 +432,11 @@ struct ClassVisitor {
 }
 
 impl ClassVisitor {
-    fn new(renaming: RenameScheme, type_: Type) -> Self {
+    fn new(renaming: RenameScheme, type_: Type, name: String) -> Self {
         Self {
             renaming,
             type_,
+            name,
             constructor: None,
             statics: Vec::new(),
             methods: Vec::new(),
@@ -455,7 +467,7 @@ impl ClassVisitor {
             explicit_method,
             explicit_static,
             fn_,
-            Some(&self.type_),
+            Some((&self.type_, &self.name)),
         )?;
 
         if f.is_static {
@@ -469,20 +481,22 @@ impl ClassVisitor {
 
     fn getter(&mut self, fn_: &mut ImplItemFn) -> SpannedResult<()> {
         let name = self.name_of(fn_)?;
-        self.accessors
-            .entry(name.clone())
-            .or_default()
-            .set_getter(name, fn_, &self.type_)?;
+        self.accessors.entry(name.clone()).or_default().set_getter(
+            name,
+            fn_,
+            (&self.type_, &self.name),
+        )?;
 
         Ok(())
     }
 
     fn setter(&mut self, fn_: &mut ImplItemFn) -> SpannedResult<()> {
         let name = self.name_of(fn_)?;
-        self.accessors
-            .entry(name.clone())
-            .or_default()
-            .set_setter(name, fn_, &self.type_)?;
+        self.accessors.entry(name.clone()).or_default().set_setter(
+            name,
+            fn_,
+            (&self.type_, &self.name),
+        )?;
         Ok(())
     }
 
@@ -577,6 +591,13 @@ impl ClassVisitor {
                     // Add all methods to the class.
                     #(#builder_methods)*
 
+                    // Expose the interface name through `Object.prototype.toString`.
+                    builder.property(
+                        boa_engine::JsSymbol::to_string_tag(),
+                        boa_engine::js_string!( #class_name ),
+                        boa_engine::property::Attribute::CONFIGURABLE,
+                    );
+
                     Ok(())
                 }
             }
@@ -666,14 +687,6 @@ pub(crate) fn class_impl(attr: TokenStream, input: TokenStream) -> TokenStream {
         }
     };
 
-    // Get all methods from the input.
-    let mut visitor = ClassVisitor::new(renaming, impl_.self_ty.as_ref().clone());
-    syn::visit_mut::visit_item_impl_mut(&mut visitor, &mut impl_);
-
-    if let Some(err) = visitor.errors {
-        return err.to_compile_error().into();
-    }
-
     let Type::Path(pa) = impl_.self_ty.as_ref() else {
         return syn::Error::new(impl_.span(), "Impossible to find the name of the class.")
             .to_compile_error()
@@ -688,7 +701,15 @@ pub(crate) fn class_impl(attr: TokenStream, input: TokenStream) -> TokenStream {
             .into();
     };
 
-    let class_impl = visitor.serialize_class_impl(&impl_.self_ty, &name.to_string());
+    // Get all methods from the input.
+    let mut visitor = ClassVisitor::new(renaming, impl_.self_ty.as_ref().clone(), name.clone());
+    syn::visit_mut::visit_item_impl_mut(&mut visitor, &mut impl_);
+
+    if let Some(err) = visitor.errors {
+        return err.to_compile_error().into();
+    }
+
+    let class_impl = visitor.serialize_class_impl(&impl_.self_ty, &name);
 
     let debug = take_path_attr(&mut impl_.attrs, "debug");
 
@@ -529,6 +529,8 @@ fn generate_conversion(
         field_list.push(name.clone());
 
         let mut from_js_with = None;
+        let mut default = None;
+        let mut required = false;
         let mut field_name = rename.rename(format!("{name}"));
         if let Some(attr) = field
             .attrs
@@ -544,33 +546,47 @@ fn generate_conversion(
                     let value = meta.value()?;
                     field_name = value.parse::<LitStr>()?.value();
                     Ok(())
+                } else if meta.path.is_ident("default") {
+                    default = Some(if meta.input.peek(syn::Token![=]) {
+                        let path = meta.value()?.parse::<LitStr>()?.parse::<syn::ExprPath>()?;
+                        quote! { #path() }
+                    } else {
+                        quote! { ::core::default::Default::default() }
+                    });
+                    Ok(())
+                } else if meta.path.is_ident("required") {
+                    required = true;
+                    Ok(())
                 } else {
                     Err(meta.error(
                         "invalid syntax in the `#[boa()]` attribute. \
                               Note that this attribute only accepts the following syntax: \
-                            `#[boa(from_js_with = \"fully::qualified::path\")]`",
+                            \n* `#[boa(from_js_with = \"fully::qualified::path\")]`\
+                            \n* `#[boa(rename = \"jsPropertyName\")]` \
+                            \n* `#[boa(default)]` \
+                            \n* `#[boa(default = \"fully::qualified::path\")]` \
+                            \n* `#[boa(required)]` \
+                            ",
                     ))
                 }
             })
             .map_err(|err| vec![err])?;
         }
 
-        let error_str = format!("cannot get property {name} of value");
-        final_fields.push(quote! {
-            let #name = match props.get(&::boa_engine::js_string!(#field_name).into()) {
-                Some(pd) => pd.value().ok_or_else(|| ::boa_engine::JsError::from(
-                        ::boa_engine::JsNativeError::typ().with_message(#error_str)
-                    ))?.clone().try_js_into(context)?,
-                None => ::boa_engine::JsValue::undefined().try_js_into(context)?,
-            };
-        });
-
-        if let Some(method) = from_js_with {
-            let ident = Ident::new(&method.value(), method.span());
-            final_fields.push(quote! {
-                let #name = #ident(&#name, context)?;
-            });
+        if required && default.is_some() {
+            return Err(vec![syn::Error::new(
+                span,
+                "a member cannot be both `required` and have a `default`",
+            )]);
         }
+
+        final_fields.push(field_conversion(
+            &name,
+            &field_name,
+            from_js_with.as_ref(),
+            default.as_ref(),
+            required,
+        ));
     }
 
     // TODO: this could possibly skip accessors. Consider using `JsObject::get` instead.
@@ -584,6 +600,59 @@ fn generate_conversion(
     })
 }
 
+/// Generates the conversion of a single field from the value of its property.
+fn field_conversion(
+    name: &Ident,
+    field_name: &str,
+    from_js_with: Option<&LitStr>,
+    default: Option<&proc_macro2::TokenStream>,
+    required: bool,
+) -> proc_macro2::TokenStream {
+    let mut tokens = Vec::with_capacity(3);
+
+    let error_str = format!("cannot get property {name} of value");
+    tokens.push(quote! {
+        let #name = match props.get(&::boa_engine::js_string!(#field_name).into()) {
+            Some(pd) => pd.value().ok_or_else(|| ::boa_engine::JsError::from(
+                    ::boa_engine::JsNativeError::typ().with_message(#error_str)
+                ))?.clone(),
+            None => ::boa_engine::JsValue::undefined(),
+        };
+    });
+
+    // Missing members are `undefined`, which either fail for required members, are
+    // replaced by their default value, or are converted like any other value.
+    if required {
+        let error_str = format!("required member {field_name} is undefined");
+        tokens.push(quote! {
+            if #name.is_undefined() {
+                return Err(::boa_engine::JsError::from(
+                    ::boa_engine::JsNativeError::typ().with_message(#error_str)
+                ));
+            }
+        });
+    }
+
+    let conversion = if let Some(method) = from_js_with {
+        let ident = Ident::new(&method.value(), method.span());
+        quote! { #ident(&#name, context)? }
+    } else {
+        quote! { #name.try_js_into(context)? }
+    };
+
+    if let Some(default) = default {
+        tokens.push(quote! {
+            let #name = if #name.is_undefined() { #default } else { #conversion };
+        });
+    } else {
+        tokens.push(quote! {
+            let #name = #conversion;
+        });
+    }
+
+    quote! { #(#tokens)* }
+}
+
 /// Generates a list of compile errors.
 #[allow(clippy::needless_pass_by_value)]
 fn to_compile_errors(errors: Vec<syn::Error>) -> proc_macro2::TokenStream {
@@ -0,0 +1,119 @@
+//! Module containing the `atob` and `btoa` functions.
+//!
+//! Both functions work on "binary strings", where each code unit holds a single byte.
+//! `btoa` throws if its input contains a code unit outside of the Latin-1 range, and
+//! `atob` implements the [forgiving-base64 decode][spec] algorithm.
+//!
+//! See <https://developer.mozilla.org/en-US/docs/Web/API/Window/btoa>.
+//!
+//! [spec]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+
+#[cfg(test)]
+mod tests;
+
+use base64::engine::{GeneralPurpose, general_purpose};
+use base64::{Engine, alphabet};
+use boa_engine::realm::Realm;
+use boa_engine::{Context, JsResult, boa_module};
+
+/// The engine decoding the base64 strings passed to `atob`, once their padding is removed.
+///
+/// The [forgiving-base64 decode][spec] algorithm discards the bits left over by the last
+/// character instead of rejecting them.
+///
+/// [spec]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+const FORGIVING: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::STANDARD,
+    general_purpose::NO_PAD.with_decode_allow_trailing_bits(true),
+);
+
+/// The [forgiving-base64 decode][spec] algorithm. Returns `None` on failure.
+///
+/// [spec]: https://infra.spec.whatwg.org/#forgiving-base64-decode
+fn forgiving_decode(data: &[u16]) -> Option<Vec<u8>> {
+    // Remove all ASCII whitespace from data.
+    let mut data = data
+        .iter()
+        .copied()
+        .filter(|c| !matches!(c, 0x09 | 0x0A | 0x0C | 0x0D | 0x20))
+        .map(u8::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+
+    // If data's length divides by 4 leaving no remainder, remove one or two trailing `=`.
+    if data.len() % 4 == 0 {
+        for _ in 0..2 {
+            if data.last() == Some(&b'=') {
+                data.pop();
+            }
+        }
+    }
+
+    // If data's length divides by 4 leaving a remainder of 1, return failure.
+    if data.len() % 4 == 1 {
+        return None;
+    }
+
+    // If data contains a code point that is not in the alphabet, return failure.
+    FORGIVING.decode(data).ok()
+}
+
+/// JavaScript module containing the `atob` and `btoa` functions.
+#[boa_module]
+pub mod js_module {
+    use base64::Engine;
+    use base64::engine::general_purpose;
+    use boa_engine::value::Convert;
+    use boa_engine::{JsResult, JsString, js_error};
+
+    /// The [`btoa()`][mdn] method creates a base64-encoded ASCII string from a binary
+    /// string, in which each character represents a byte.
+    ///
+    /// # Errors
+    /// Returns an `InvalidCharacterError` if `data` contains a character whose code
+    /// point is greater than `0xFF`.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/btoa
+    pub fn btoa(Convert(data): Convert<JsString>) -> JsResult<JsString> {
+        let bytes = data
+            .iter()
+            .map(u8::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| {
+                js_error!(
+                    Error: "InvalidCharacterError: the string contains characters outside of the Latin1 range"
+                )
+            })?;
+
+        Ok(JsString::from(general_purpose::STANDARD.encode(bytes)))
+    }
+
+    /// The [`atob()`][mdn] method decodes a base64-encoded string to a binary string, in
+    /// which each character represents a byte.
+    ///
+    /// # Errors
+    /// Returns an `InvalidCharacterError` if `data` is not valid base64.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/atob
+    pub fn atob(Convert(data): Convert<JsString>) -> JsResult<JsString> {
+        let bytes = super::forgiving_decode(&data.to_vec()).ok_or_else(
+            || js_error!(Error: "InvalidCharacterError: the string to be decoded is not correctly encoded"),
+        )?;
+
+        Ok(JsString::from(
+            bytes
+                .into_iter()
+                .map(u16::from)
+                .collect::<Vec<_>>()
+                .as_slice(),
+        ))
+    }
+}
+
+/// Register the `atob` and `btoa` functions in the realm or context.
+///
+/// # Errors
+/// Returns an error if the functions cannot be registered.
+pub fn register(realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+    js_module::boa_register(realm, context)
+}
@@ -0,0 +1,29 @@
+use crate::test::{TestAction, run_test_actions};
+use indoc::indoc;
+
+#[test]
+fn btoa_and_atob() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            super::register(None, context).unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            assertEq(btoa(""), "");
+            assertEq(btoa("f"), "Zg==");
+            assertEq(btoa("fo"), "Zm8=");
+            assertEq(btoa("foo"), "Zm9v");
+            assertEq(btoa("\xff\xfe\x00"), "//4A");
+            assertThrows(() => btoa("€"));
+
+            assertEq(atob("Zm9v"), "foo");
+            assertEq(atob(" Zm 8\n="), "fo");
+            assertEq(atob("Zg"), "f");
+            assertEq(atob("Zh=="), "f");
+            assertEq(atob("//4A"), "\xff\xfe\x00");
+            assertThrows(() => atob("Zm9vY"));
+            assertThrows(() => atob("Zg="));
+            assertThrows(() => atob("Zm9v!"));
+        "#}),
+    ]);
+}
@@ -24,10 +24,7 @@ use boa_engine::{
 };
 use boa_gc::{Finalize, Trace};
 use rustc_hash::FxHashMap;
-use std::{
-    cell::RefCell, collections::hash_map::Entry, fmt::Write as _, io::Write, rc::Rc,
-    time::SystemTime,
-};
+use std::{cell::RefCell, collections::hash_map::Entry, fmt::Write as _, io::Write, rc::Rc};
 
 /// A trait that can be used to forward console logs to an implementation.
 pub trait Logger: Trace {
@@ -697,12 +694,9 @@ impl Console {
         Ok(JsValue::undefined())
     }
 
-    /// Returns current system time in ms.
-    fn system_time_in_ms() -> u128 {
-        let now = SystemTime::now();
-        now.duration_since(SystemTime::UNIX_EPOCH)
-            .expect("negative duration")
-            .as_millis()
+    /// Returns the current time of the context's clock in ms.
+    fn system_time_in_ms(context: &Context) -> u128 {
+        u128::from(context.clock().now().millis_since_epoch())
     }
 
     /// `console.time(label)`
@@ -728,7 +722,7 @@ impl Console {
         };
 
         if let Entry::Vacant(e) = console.state.timer_map.entry(label.clone()) {
-            let time = Self::system_time_in_ms();
+            let time = Self::system_time_in_ms(context);
             e.insert(time);
         } else {
             logger.warn(
@@ -764,7 +758,7 @@ impl Console {
         };
 
         if let Some(t) = console.state.timer_map.get(&label) {
-            let time = Self::system_time_in_ms();
+            let time = Self::system_time_in_ms(context);
             let mut concat = format!("{}: {} ms", label.to_std_string_escaped(), time - t);
             for msg in args.iter().skip(1) {
                 concat = concat + " " + &msg.display().to_string();
@@ -804,7 +798,7 @@ impl Console {
         };
 
         if let Some(t) = console.state.timer_map.remove(&label) {
-            let time = Self::system_time_in_ms();
+            let time = Self::system_time_in_ms(context);
             logger.info(
                 format!(
                     "{}: {} ms - timer removed",
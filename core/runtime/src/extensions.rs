@@ -48,6 +48,16 @@ impl RuntimeExtension for EncodingExtension {
     }
 }
 
+/// Register the `atob` and `btoa` functions.
+#[derive(Copy, Clone, Debug)]
+pub struct Base64Extension;
+
+impl RuntimeExtension for Base64Extension {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::base64::register(realm, context)
+    }
+}
+
 /// Register the `structuredClone` function.
 #[derive(Copy, Clone, Debug)]
 pub struct StructuredCloneExtension;
@@ -58,6 +68,28 @@ impl RuntimeExtension for StructuredCloneExtension {
     }
 }
 
+/// Register the `reportError` function and the error event classes with the specified
+/// reporter. Use [`ErrorReportingExtension::default()`] to print unhandled errors to stderr.
+///
+/// [`crate::register`] always registers the default reporter; passing this extension to it
+/// replaces that reporter.
+#[derive(Debug)]
+pub struct ErrorReportingExtension<R: crate::report::ErrorReporter>(pub R);
+
+impl Default for ErrorReportingExtension<crate::report::DefaultErrorReporter> {
+    fn default() -> Self {
+        ErrorReportingExtension(crate::report::DefaultErrorReporter)
+    }
+}
+
+impl<R: crate::report::ErrorReporter + Debug + 'static> RuntimeExtension
+    for ErrorReportingExtension<R>
+{
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::report::register(self.0, realm, context)
+    }
+}
+
 /// Register the URL classes.
 #[cfg(feature = "url")]
 #[derive(Copy, Clone, Debug)]
@@ -99,6 +131,25 @@ impl<F: crate::fetch::Fetcher + Debug + 'static> RuntimeExtension for FetchExten
     }
 }
 
+/// Register the `open()`, `close()` and `matchMedia()` functions, the `MediaQueryList`
+/// class, and the `closed` and viewport properties with the specified
+/// [`crate::window::WindowHost`]. Use [`WindowExtension::default()`] to block every window
+/// scripts try to open.
+#[derive(Debug)]
+pub struct WindowExtension<H: crate::window::WindowHost>(pub H);
+
+impl Default for WindowExtension<crate::window::DefaultWindowHost> {
+    fn default() -> Self {
+        WindowExtension(crate::window::DefaultWindowHost)
+    }
+}
+
+impl<H: crate::window::WindowHost + Debug + 'static> RuntimeExtension for WindowExtension<H> {
+    fn register(self, realm: Option<Realm>, context: &mut Context) -> JsResult<()> {
+        crate::window::register(self.0, realm, context)
+    }
+}
+
 macro_rules! decl_runtime_ext_tuple {
     ($first_name: ident : $first_type: ident) => {
         impl<$first_type: RuntimeExtension> RuntimeExtension for ($first_type,) {
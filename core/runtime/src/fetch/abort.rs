@@ -0,0 +1,206 @@
+//! Propagation of `AbortSignal` aborts to [`Fetcher`] implementations.
+//!
+//! When a `fetch()` call is given a signal, the fetch algorithm creates an [`AbortToken`]
+//! that is aborted as soon as the signal is. The token is attached to the [`JsRequest`]
+//! handed to the fetcher, so the transport can cancel the in-flight request instead of
+//! letting it run to completion after the promise was already rejected.
+//!
+//! See <https://fetch.spec.whatwg.org/#abort-fetch>.
+//!
+//! [`Fetcher`]: super::Fetcher
+//! [`JsRequest`]: super::request::JsRequest
+
+use boa_engine::object::builtins::JsFunction;
+use boa_engine::{
+    Context, Finalize, JsError, JsObject, JsResult, JsValue, NativeFunction, Trace, js_error,
+    js_string,
+};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::future::Future;
+use std::rc::Rc;
+use std::task::{Poll, Waker};
+
+#[derive(Default)]
+struct Inner {
+    aborted: Cell<bool>,
+    waker: RefCell<Option<Waker>>,
+    callbacks: RefCell<Vec<Box<dyn FnOnce()>>>,
+}
+
+/// A cancellation token for a single fetch, aborted when the fetch's `AbortSignal` is.
+///
+/// Tokens are cheap to clone, and all the clones share the same state.
+#[derive(Clone, Default)]
+pub struct AbortToken(Rc<Inner>);
+
+impl fmt::Debug for AbortToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AbortToken")
+            .field("aborted", &self.is_aborted())
+            .finish_non_exhaustive()
+    }
+}
+
+impl AbortToken {
+    /// Creates a new token, not aborted yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if the fetch was aborted.
+    #[must_use]
+    pub fn is_aborted(&self) -> bool {
+        self.0.aborted.get()
+    }
+
+    /// Aborts the fetch, running the registered callbacks and waking the pending
+    /// [`AbortToken::aborted`] future. Aborting a token more than once does nothing.
+    pub fn abort(&self) {
+        if self.0.aborted.replace(true) {
+            return;
+        }
+
+        let callbacks = self.0.callbacks.take();
+        for callback in callbacks {
+            callback();
+        }
+        if let Some(waker) = self.0.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Registers `callback` to be called once the fetch is aborted, e.g. to cancel the
+    /// request in the transport. The callback is called right away if the fetch was
+    /// already aborted.
+    pub fn on_abort(&self, callback: impl FnOnce() + 'static) {
+        if self.is_aborted() {
+            callback();
+        } else {
+            self.0.callbacks.borrow_mut().push(Box::new(callback));
+        }
+    }
+
+    /// Returns a future resolving once the fetch is aborted.
+    ///
+    /// The token only keeps the waker of the last poll, so the futures of a token should
+    /// be awaited from the task running the fetch.
+    pub fn aborted(&self) -> impl Future<Output = ()> + 'static {
+        let token = self.clone();
+        std::future::poll_fn(move |cx| {
+            if token.is_aborted() {
+                return Poll::Ready(());
+            }
+            let mut slot = token.0.waker.borrow_mut();
+            match &mut *slot {
+                Some(waker) => waker.clone_from(cx.waker()),
+                None => *slot = Some(cx.waker().clone()),
+            }
+            Poll::Pending
+        })
+    }
+}
+
+/// The captures of the `abort` event listener registered on a signal.
+#[derive(Clone, Trace, Finalize)]
+struct ListenerCaptures(#[unsafe_ignore_trace] AbortToken);
+
+/// The error a fetch rejects with once `signal` is aborted, i.e. the signal's reason.
+pub(crate) fn abort_reason(signal: &JsObject, context: &mut Context) -> JsError {
+    match signal.get(js_string!("reason"), context) {
+        Ok(reason) if !reason.is_undefined() => JsError::from_opaque(reason),
+        Ok(_) => js_error!(Error: "AbortError: the fetch was aborted"),
+        Err(err) => err,
+    }
+}
+
+/// Returns `true` if `signal` is already aborted.
+pub(crate) fn is_signal_aborted(signal: &JsObject, context: &mut Context) -> JsResult<bool> {
+    Ok(signal.get(js_string!("aborted"), context)?.to_boolean())
+}
+
+/// The `abort` event listener aborting a token, registered on the signal of a fetch.
+pub(crate) struct SignalListener {
+    signal: JsObject,
+    listener: JsFunction,
+}
+
+impl SignalListener {
+    /// Removes the listener from the signal, once the fetch settled and can't be aborted
+    /// anymore.
+    ///
+    /// # Errors
+    /// Returns an error if removing the listener fails.
+    pub(crate) fn remove(self, context: &mut Context) -> JsResult<()> {
+        let remove_event_listener = self
+            .signal
+            .get(js_string!("removeEventListener"), context)?
+            .as_object()
+            .and_then(JsFunction::from_object);
+        if let Some(remove_event_listener) = remove_event_listener {
+            remove_event_listener.call(
+                &self.signal.into(),
+                &[js_string!("abort").into(), self.listener.into()],
+                context,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Creates a token aborted once `signal` fires its `abort` event, along with the listener
+/// to remove from the signal once the fetch settles.
+///
+/// # Errors
+/// Returns an error if `signal` doesn't have an `addEventListener` method, or if adding
+/// the listener fails.
+pub(crate) fn follow_signal(
+    signal: &JsObject,
+    context: &mut Context,
+) -> JsResult<(AbortToken, SignalListener)> {
+    let add_event_listener = signal
+        .get(js_string!("addEventListener"), context)?
+        .as_object()
+        .and_then(JsFunction::from_object)
+        .ok_or_else(|| js_error!(TypeError: "signal is not an AbortSignal"))?;
+
+    let token = AbortToken::new();
+    let listener = NativeFunction::from_copy_closure_with_captures(
+        |_, _, ListenerCaptures(token), _| {
+            token.abort();
+            Ok(JsValue::undefined())
+        },
+        ListenerCaptures(token.clone()),
+    )
+    .to_js_function(context.realm());
+
+    add_event_listener.call(
+        &signal.clone().into(),
+        &[js_string!("abort").into(), listener.clone().into()],
+        context,
+    )?;
+
+    Ok((
+        token,
+        SignalListener {
+            signal: signal.clone(),
+            listener,
+        },
+    ))
+}
+
+/// Races `future` against the abort of `token`, rejecting with the reason of `signal`
+/// if the fetch is aborted first.
+pub(crate) async fn abortable<T>(
+    future: impl Future<Output = JsResult<T>>,
+    token: &AbortToken,
+    signal: &JsObject,
+    context: &RefCell<&mut Context>,
+) -> JsResult<T> {
+    let aborted = async {
+        token.aborted().await;
+        Err(abort_reason(signal, &mut context.borrow_mut()))
+    };
+    futures_lite::future::or(aborted, future).await
+}
@@ -0,0 +1,189 @@
+//! The [`Body` mixin][spec] shared by the `Request` and `Response` classes.
+//!
+//! A body can only be read once: reading it through any of the `arrayBuffer()`, `bytes()`,
+//! `json()` or `text()` methods marks it as used, and reading it again rejects with a
+//! `TypeError`.
+//!
+//! [spec]: https://fetch.spec.whatwg.org/#body-mixin
+use boa_engine::object::builtins::{
+    JsArrayBuffer, JsDataView, JsPromise, JsTypedArray, JsUint8Array,
+};
+use boa_engine::{Context, JsError, JsNativeError, JsResult, JsString, JsValue, js_error};
+use std::borrow::Cow;
+use std::rc::Rc;
+
+/// The format a body is read as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BodyFormat {
+    ArrayBuffer,
+    Bytes,
+    Json,
+    Text,
+}
+
+/// The body of a `Request` or a `Response`.
+#[derive(Debug, Clone, Default)]
+pub struct Body {
+    /// The bytes of the body, or `None` for a null body.
+    bytes: Option<Rc<Vec<u8>>>,
+    /// Whether the body was read.
+    used: bool,
+}
+
+impl Body {
+    /// Create a body containing `bytes`.
+    #[must_use]
+    pub fn new(bytes: Vec<u8>) -> Self {
+        Self {
+            bytes: Some(Rc::new(bytes)),
+            used: false,
+        }
+    }
+
+    /// Create a null body.
+    #[must_use]
+    pub fn null() -> Self {
+        Self::default()
+    }
+
+    /// Returns `true` if this is a null body.
+    #[must_use]
+    pub fn is_null(&self) -> bool {
+        self.bytes.is_none()
+    }
+
+    /// Returns `true` if the body was already read. Null bodies are never used.
+    #[must_use]
+    pub fn is_used(&self) -> bool {
+        self.used
+    }
+
+    /// Mark a non-null body as used without reading it, e.g. when it is transferred to
+    /// another request.
+    pub(crate) fn disturb(&mut self) {
+        if self.bytes.is_some() {
+            self.used = true;
+        }
+    }
+
+    /// Return a copy of the bytes of the body without reading it. A null body has no bytes.
+    #[must_use]
+    pub fn bytes(&self) -> Rc<Vec<u8>> {
+        self.bytes.clone().unwrap_or_default()
+    }
+
+    /// [Consume][spec] the body, returning a promise resolving to its content in `format`.
+    ///
+    /// The returned promise rejects with a `TypeError` if the body was already used.
+    ///
+    /// [spec]: https://fetch.spec.whatwg.org/#concept-body-consume-body
+    pub(crate) fn consume(&mut self, format: BodyFormat, context: &mut Context) -> JsPromise {
+        if self.used {
+            return JsPromise::reject(
+                js_error!(TypeError: "body has already been consumed"),
+                context,
+            );
+        }
+
+        // Reading a null body yields an empty byte sequence, and doesn't disturb it.
+        let bytes = match &self.bytes {
+            Some(bytes) => {
+                self.used = true;
+                bytes.clone()
+            }
+            None => Rc::default(),
+        };
+
+        JsPromise::from_async_fn(
+            async move |context| package_data(&bytes, format, &mut context.borrow_mut()),
+            context,
+        )
+    }
+}
+
+/// [Package the bytes][spec] of a body as a JavaScript value of the requested format.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#concept-body-package-data
+fn package_data(bytes: &[u8], format: BodyFormat, context: &mut Context) -> JsResult<JsValue> {
+    match format {
+        BodyFormat::ArrayBuffer => {
+            JsArrayBuffer::from_byte_block(bytes.to_vec(), context).map(Into::into)
+        }
+        BodyFormat::Bytes => {
+            JsUint8Array::from_iter(bytes.iter().copied(), context).map(Into::into)
+        }
+        BodyFormat::Json => {
+            let json = serde_json::from_str::<serde_json::Value>(&utf8_decode(bytes))
+                .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
+
+            JsValue::from_json(&json, context)
+        }
+        BodyFormat::Text => Ok(JsString::from(utf8_decode(bytes).as_ref()).into()),
+    }
+}
+
+/// [UTF-8 decode][spec] the bytes of a body, skipping a leading byte order mark and
+/// replacing invalid sequences with U+FFFD.
+///
+/// [spec]: https://encoding.spec.whatwg.org/#utf-8-decode
+fn utf8_decode(bytes: &[u8]) -> Cow<'_, str> {
+    let bytes = bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes);
+    String::from_utf8_lossy(bytes)
+}
+
+/// [Extract][spec] the bytes of a body from a JavaScript value, along with the
+/// `Content-Type` the value implies, if any.
+///
+/// Strings, `ArrayBuffer`s, typed arrays and `DataView`s are supported.
+///
+/// # Errors
+/// Returns a `TypeError` if the value is not of a supported type.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#concept-bodyinit-extract
+pub(crate) fn extract_body(
+    value: &JsValue,
+    context: &mut Context,
+) -> JsResult<(Vec<u8>, Option<&'static str>)> {
+    if let Some(string) = value.as_string() {
+        // Bodies are `USVString`s, so lone surrogates become U+FFFD instead of throwing.
+        let string = string.to_std_string_lossy();
+        return Ok((string.into_bytes(), Some("text/plain;charset=UTF-8")));
+    }
+
+    let Some(object) = value.as_object() else {
+        return Err(js_error!(TypeError: "body is not a supported type"));
+    };
+
+    let (buffer, offset, length) = if let Ok(buffer) = JsArrayBuffer::from_object(object.clone()) {
+        let length = buffer.byte_length();
+        (buffer, 0, length)
+    } else if let Ok(array) = JsTypedArray::from_object(object.clone()) {
+        let offset = array.byte_offset(context)?;
+        let length = array.byte_length(context)?;
+        let buffer = array.buffer(context)?;
+        (buffer_from_value(&buffer)?, offset, length)
+    } else if let Ok(view) = JsDataView::from_object(object.clone()) {
+        let offset = usize::try_from(view.byte_offset(context)?).map_err(JsError::from_rust)?;
+        let length = usize::try_from(view.byte_length(context)?).map_err(JsError::from_rust)?;
+        let buffer = view.buffer(context)?;
+        (buffer_from_value(&buffer)?, offset, length)
+    } else {
+        return Err(js_error!(TypeError: "body is not a supported type"));
+    };
+
+    let data = buffer
+        .data()
+        .ok_or_else(|| js_error!(TypeError: "body buffer is detached"))?;
+    let bytes = data
+        .get(offset..offset + length)
+        .ok_or_else(|| js_error!(TypeError: "body view is out of bounds"))?;
+
+    Ok((bytes.to_vec(), None))
+}
+
+fn buffer_from_value(buffer: &JsValue) -> JsResult<JsArrayBuffer> {
+    let Some(buffer) = buffer.as_object() else {
+        return Err(js_error!(TypeError: "body view has an invalid buffer"));
+    };
+    JsArrayBuffer::from_object(buffer)
+}
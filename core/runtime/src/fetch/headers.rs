@@ -41,11 +41,134 @@ fn to_header_value(value: impl AsRef<str>) -> JsResult<HeaderValue> {
         .map_err(|_| js_error!("Cannot convert value to header string as it is not valid ASCII."))
 }
 
+/// Returns whether `name` is a [forbidden request-header][spec] when set to `value`.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#forbidden-request-header
+pub(crate) fn is_forbidden_request_header(name: &HeaderName, value: &HeaderValue) -> bool {
+    const FORBIDDEN_NAMES: &[&str] = &[
+        "accept-charset",
+        "accept-encoding",
+        "access-control-request-headers",
+        "access-control-request-method",
+        "connection",
+        "content-length",
+        "cookie",
+        "cookie2",
+        "date",
+        "dnt",
+        "expect",
+        "host",
+        "keep-alive",
+        "origin",
+        "referer",
+        "set-cookie",
+        "te",
+        "trailer",
+        "transfer-encoding",
+        "upgrade",
+        "via",
+    ];
+
+    // `HeaderName`s are always lowercase.
+    let name = name.as_str();
+    if FORBIDDEN_NAMES.contains(&name) || name.starts_with("proxy-") || name.starts_with("sec-") {
+        return true;
+    }
+
+    // Headers that can be used to override the request method can't smuggle a forbidden one.
+    if matches!(
+        name,
+        "x-http-method" | "x-http-method-override" | "x-method-override"
+    ) {
+        return value.to_str().map_or(true, |value| {
+            value.split(',').any(|method| {
+                let method = method.trim();
+                ["CONNECT", "TRACE", "TRACK"]
+                    .iter()
+                    .any(|forbidden| method.eq_ignore_ascii_case(forbidden))
+            })
+        });
+    }
+
+    false
+}
+
+/// Returns whether `name` is a [forbidden response-header name][spec].
+///
+/// [spec]: https://fetch.spec.whatwg.org/#forbidden-response-header-name
+fn is_forbidden_response_header_name(name: &HeaderName) -> bool {
+    matches!(name.as_str(), "set-cookie" | "set-cookie2")
+}
+
+/// Returns whether `name` can be used with the `request-no-cors` guard.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#no-cors-safelisted-request-header-name
+fn is_no_cors_safelisted_request_header_name(name: &HeaderName) -> bool {
+    matches!(
+        name.as_str(),
+        "accept" | "accept-language" | "content-language" | "content-type"
+    )
+}
+
+/// Returns whether `name` with `value` is a [no-CORS-safelisted request-header][spec].
+///
+/// [spec]: https://fetch.spec.whatwg.org/#no-cors-safelisted-request-header
+fn is_no_cors_safelisted_request_header(name: &HeaderName, value: &[u8]) -> bool {
+    fn is_cors_unsafe_byte(byte: u8) -> bool {
+        (byte < 0x20 && byte != b'\t') || b"\"():<>?@[\\]{}\x7F".contains(&byte)
+    }
+
+    if !is_no_cors_safelisted_request_header_name(name) || value.len() > 128 {
+        return false;
+    }
+
+    match name.as_str() {
+        "accept" => !value.iter().copied().any(is_cors_unsafe_byte),
+        "accept-language" | "content-language" => value
+            .iter()
+            .all(|b| b.is_ascii_alphanumeric() || b" *,-.;=".contains(b)),
+        "content-type" => {
+            if value.iter().copied().any(is_cors_unsafe_byte) {
+                return false;
+            }
+            // Combined values are separated by commas, the last MIME type wins.
+            let mime = value.rsplit(|b| *b == b',').next().unwrap_or_default();
+            let essence = mime.split(|b| *b == b';').next().unwrap_or_default();
+            let essence = String::from_utf8_lossy(essence).trim().to_ascii_lowercase();
+            matches!(
+                essence.as_str(),
+                "application/x-www-form-urlencoded" | "multipart/form-data" | "text/plain"
+            )
+        }
+        _ => false,
+    }
+}
+
+/// The [guard][spec] of a `Headers` object, restricting which headers scripts can modify.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#concept-headers-guard
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HeadersGuard {
+    /// Every header can be modified.
+    #[default]
+    None,
+    /// No header can be modified; modifications throw a `TypeError`.
+    Immutable,
+    /// Forbidden request-headers are silently ignored.
+    Request,
+    /// Only no-CORS-safelisted request-headers can be modified.
+    RequestNoCors,
+    /// Forbidden response-header names (`Set-Cookie`) are silently ignored.
+    Response,
+}
+
 /// A JavaScript wrapper for the `Headers` object.
 #[derive(Debug, Default, Clone, JsData, Trace, Finalize)]
 pub struct JsHeaders {
     #[unsafe_ignore_trace]
     headers: Rc<RefCell<HttpHeaderMap>>,
+    #[unsafe_ignore_trace]
+    guard: HeadersGuard,
 }
 
 impl TryFromJs for JsHeaders {
@@ -71,6 +194,68 @@ impl JsHeaders {
     pub fn from_http(http: HttpHeaderMap) -> Self {
         Self {
             headers: Rc::new(RefCell::new(http)),
+            guard: HeadersGuard::None,
+        }
+    }
+
+    /// Returns this object with its guard set to `guard`.
+    #[must_use]
+    pub fn with_guard(mut self, guard: HeadersGuard) -> Self {
+        self.guard = guard;
+        self
+    }
+
+    /// Returns the guard of this object.
+    #[must_use]
+    pub fn guard(&self) -> HeadersGuard {
+        self.guard
+    }
+
+    /// Returns a copy of the inner [`http::HeaderMap`].
+    #[must_use]
+    pub fn to_http(&self) -> HttpHeaderMap {
+        self.headers.borrow().clone()
+    }
+
+    /// Returns the headers [sorted and combined][spec]: sorted by name, with the values
+    /// of each name joined, except for `Set-Cookie` whose values are kept separate.
+    /// This is the order in which headers are iterated from JavaScript.
+    ///
+    /// [spec]: https://fetch.spec.whatwg.org/#concept-header-list-sort-and-combine
+    fn sort_and_combine(&self) -> Vec<(JsString, JsString)> {
+        let headers = self.headers.borrow();
+        let mut names = headers.keys().collect::<Vec<_>>();
+        names.sort_unstable_by(|a, b| a.as_str().cmp(b.as_str()));
+
+        let mut list = Vec::with_capacity(names.len());
+        for name in names {
+            let values = headers
+                .get_all(name)
+                .iter()
+                .map(|v| v.to_str().unwrap_or(""));
+            if name == http::header::SET_COOKIE {
+                list.extend(values.map(|v| (JsString::from(name.as_str()), JsString::from(v))));
+            } else {
+                let combined = values.collect::<Vec<_>>().join(", ");
+                list.push((JsString::from(name.as_str()), JsString::from(combined)));
+            }
+        }
+        list
+    }
+
+    /// [Validates][spec] a header against the guard. Returns `false` if the modification
+    /// must be silently ignored.
+    ///
+    /// # Errors
+    /// If the guard is [`HeadersGuard::Immutable`], an error is returned.
+    ///
+    /// [spec]: https://fetch.spec.whatwg.org/#headers-validate
+    fn validate(&self, name: &HeaderName, value: &HeaderValue) -> JsResult<bool> {
+        match self.guard {
+            HeadersGuard::Immutable => Err(js_error!(TypeError: "Headers are immutable.")),
+            HeadersGuard::Request if is_forbidden_request_header(name, value) => Ok(false),
+            HeadersGuard::Response if is_forbidden_response_header_name(name) => Ok(false),
+            _ => Ok(true),
         }
     }
 }
@@ -136,6 +321,21 @@ impl JsHeaders {
     pub fn append(&mut self, key: Convert<String>, value: Convert<String>) -> JsResult<()> {
         let key = to_header_name(key.as_ref())?;
         let value = to_header_value(value.as_ref())?;
+        if !self.validate(&key, &value)? {
+            return Ok(());
+        }
+        if self.guard == HeadersGuard::RequestNoCors {
+            // The combined value of the header must stay safelisted.
+            let mut combined = Vec::new();
+            for existing in self.headers.borrow().get_all(&key) {
+                combined.extend_from_slice(existing.as_bytes());
+                combined.extend_from_slice(b", ");
+            }
+            combined.extend_from_slice(value.as_bytes());
+            if !is_no_cors_safelisted_request_header(&key, &combined) {
+                return Ok(());
+            }
+        }
         if !self.headers.borrow_mut().append(&key, value.clone()) {
             self.headers.borrow_mut().insert(key, value);
         }
@@ -148,6 +348,15 @@ impl JsHeaders {
     /// If the key is not valid ASCII, an error is returned.
     pub fn delete(&mut self, key: Convert<String>) -> JsResult<()> {
         let key = to_header_name(key.as_ref())?;
+        if !self.validate(&key, &HeaderValue::from_static(""))? {
+            return Ok(());
+        }
+        if self.guard == HeadersGuard::RequestNoCors
+            && !is_no_cors_safelisted_request_header_name(&key)
+            && key != http::header::RANGE
+        {
+            return Ok(());
+        }
         self.headers.borrow_mut().remove(key);
         Ok(())
     }
@@ -156,14 +365,9 @@ impl JsHeaders {
     // TODO: This should return a JsIterator, but not such thing exists yet.
     pub fn entries(&self, context: &mut Context) -> JsValue {
         JsArray::from_iter(
-            self.headers
-                .borrow()
-                .iter()
-                .map(|(k, v)| {
-                    let k: JsValue = JsString::from(k.as_str()).into();
-                    let v: JsValue = JsString::from(v.to_str().unwrap_or_default()).into();
-                    JsArray::from_iter([k, v], context).into()
-                })
+            self.sort_and_combine()
+                .into_iter()
+                .map(|(k, v)| JsArray::from_iter([k.into(), v.into()], context).into())
                 .collect::<Vec<_>>(),
             context,
         )
@@ -184,9 +388,8 @@ impl JsHeaders {
     ) -> JsResult<()> {
         let object = this.inner().upcast();
         let this_arg = this_arg.unwrap_or_default();
-        for (k, v) in this.clone_inner().headers.borrow().iter() {
-            let k = JsString::from(k.as_str());
-            let v = JsString::from(v.to_str().unwrap_or(""));
+        // Iterate over a snapshot, as the callback may modify the headers.
+        for (k, v) in this.clone_inner().sort_and_combine() {
             callback.call_with_this(&this_arg, context, (v, k, object.clone()))?;
         }
         Ok(())
@@ -209,11 +412,11 @@ impl JsHeaders {
             .map(|v| v.to_str().unwrap_or(""))
             // Use an Option<String> to accumulate the values into a single string,
             // if there are any. Otherwise, we return None.
-            // Cannot use `join(",")` as we need to return undefined if none is found.
+            // Cannot use `join(", ")` as we need to return undefined if none is found.
             .fold(None, |mut acc, v| {
                 let str = acc.get_or_insert_with(String::new);
                 if !str.is_empty() {
-                    str.push(',');
+                    str.push_str(", ");
                 }
                 str.push_str(v);
                 acc
@@ -243,12 +446,10 @@ impl JsHeaders {
 
     /// Returns an iterator allowing you to go through all keys of the key/value pairs
     /// contained in this object.
-    #[allow(clippy::unused_self)]
     fn keys(&self) -> Vec<JsString> {
-        self.headers
-            .borrow()
-            .keys()
-            .map(|k| JsString::from(k.as_str()))
+        self.sort_and_combine()
+            .into_iter()
+            .map(|(k, _)| k)
             .collect()
     }
 
@@ -257,15 +458,22 @@ impl JsHeaders {
     fn set(&mut self, key: Convert<String>, value: Convert<String>) -> JsResult<()> {
         let key = to_header_name(key.as_ref())?;
         let value = to_header_value(value.as_ref())?;
+        if !self.validate(&key, &value)? {
+            return Ok(());
+        }
+        if self.guard == HeadersGuard::RequestNoCors
+            && !is_no_cors_safelisted_request_header(&key, value.as_bytes())
+        {
+            return Ok(());
+        }
         self.headers.borrow_mut().insert(key, value);
         Ok(())
     }
 
     fn values(&self) -> Vec<JsString> {
-        self.headers
-            .borrow()
-            .values()
-            .map(|v| JsString::from(v.to_str().unwrap_or("")))
+        self.sort_and_combine()
+            .into_iter()
+            .map(|(_, v)| v)
             .collect()
     }
 }
@@ -0,0 +1,255 @@
+//! Content-type sniffing, following the core rules of the [MIME Sniffing standard][spec].
+//!
+//! Sniffing is used to compute the MIME type of a resource that doesn't have an explicit
+//! one, e.g. a response without a `Content-Type` header. Only the first
+//! [`RESOURCE_HEADER_LENGTH`] bytes of the resource are inspected.
+//!
+//! [spec]: https://mimesniff.spec.whatwg.org/
+
+/// The maximum number of bytes of a resource inspected when sniffing.
+///
+/// See <https://mimesniff.spec.whatwg.org/#reading-the-resource-header>.
+pub const RESOURCE_HEADER_LENGTH: usize = 1445;
+
+/// A byte pattern identifying a MIME type.
+///
+/// See <https://mimesniff.spec.whatwg.org/#pattern-matching-algorithm>.
+struct Pattern {
+    bytes: &'static [u8],
+    mask: &'static [u8],
+    /// Whether leading whitespace bytes are skipped before matching.
+    skip_whitespace: bool,
+    mime: &'static str,
+}
+
+impl Pattern {
+    const fn new(bytes: &'static [u8], mask: &'static [u8], mime: &'static str) -> Self {
+        Self {
+            bytes,
+            mask,
+            skip_whitespace: false,
+            mime,
+        }
+    }
+
+    fn matches(&self, input: &[u8]) -> bool {
+        let input = if self.skip_whitespace {
+            let start = input
+                .iter()
+                .position(|b| !is_whitespace(*b))
+                .unwrap_or(input.len());
+            &input[start..]
+        } else {
+            input
+        };
+
+        input.len() >= self.bytes.len()
+            && self
+                .bytes
+                .iter()
+                .zip(self.mask)
+                .zip(input)
+                .all(|((pattern, mask), byte)| byte & mask == *pattern)
+    }
+}
+
+/// The patterns of scriptable MIME types, matched only when sniffing scriptable types is
+/// allowed. HTML tags are handled by [`matches_html_tag`].
+const SCRIPTABLE_PATTERNS: &[Pattern] = &[
+    Pattern {
+        skip_whitespace: true,
+        ..Pattern::new(b"<?xml", b"\xFF\xFF\xFF\xFF\xFF", "text/xml")
+    },
+    Pattern::new(b"%PDF-", b"\xFF\xFF\xFF\xFF\xFF", "application/pdf"),
+];
+
+/// The HTML tags identifying `text/html`, in uppercase. Each must be followed by a
+/// tag-terminating byte.
+const HTML_TAGS: &[&[u8]] = &[
+    b"<!DOCTYPE HTML",
+    b"<HTML",
+    b"<HEAD",
+    b"<SCRIPT",
+    b"<IFRAME",
+    b"<H1",
+    b"<DIV",
+    b"<FONT",
+    b"<TABLE",
+    b"<A",
+    b"<STYLE",
+    b"<TITLE",
+    b"<B",
+    b"<BODY",
+    b"<BR",
+    b"<P",
+    b"<!--",
+];
+
+/// The patterns of MIME types that are never scriptable, and of the byte order marks.
+const NON_SCRIPTABLE_PATTERNS: &[Pattern] = &[
+    Pattern::new(
+        b"%!PS-Adobe-",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "application/postscript",
+    ),
+    Pattern::new(b"\xFE\xFF\x00\x00", b"\xFF\xFF\x00\x00", "text/plain"),
+    Pattern::new(b"\xFF\xFE\x00\x00", b"\xFF\xFF\x00\x00", "text/plain"),
+    Pattern::new(b"\xEF\xBB\xBF\x00", b"\xFF\xFF\xFF\x00", "text/plain"),
+];
+
+/// See <https://mimesniff.spec.whatwg.org/#matching-an-image-type-pattern>.
+const IMAGE_PATTERNS: &[Pattern] = &[
+    Pattern::new(b"\x00\x00\x01\x00", b"\xFF\xFF\xFF\xFF", "image/x-icon"),
+    Pattern::new(b"\x00\x00\x02\x00", b"\xFF\xFF\xFF\xFF", "image/x-icon"),
+    Pattern::new(b"BM", b"\xFF\xFF", "image/bmp"),
+    Pattern::new(b"GIF87a", b"\xFF\xFF\xFF\xFF\xFF\xFF", "image/gif"),
+    Pattern::new(b"GIF89a", b"\xFF\xFF\xFF\xFF\xFF\xFF", "image/gif"),
+    Pattern::new(
+        b"RIFF\x00\x00\x00\x00WEBPVP",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF\xFF\xFF",
+        "image/webp",
+    ),
+    Pattern::new(
+        b"\x89PNG\r\n\x1A\n",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "image/png",
+    ),
+    Pattern::new(b"\xFF\xD8\xFF", b"\xFF\xFF\xFF", "image/jpeg"),
+];
+
+/// See <https://mimesniff.spec.whatwg.org/#matching-an-audio-or-video-type-pattern>.
+const AUDIO_VIDEO_PATTERNS: &[Pattern] = &[
+    Pattern::new(
+        b"FORM\x00\x00\x00\x00AIFF",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "audio/aiff",
+    ),
+    Pattern::new(b"ID3", b"\xFF\xFF\xFF", "audio/mpeg"),
+    Pattern::new(b"OggS\x00", b"\xFF\xFF\xFF\xFF\xFF", "application/ogg"),
+    Pattern::new(
+        b"MThd\x00\x00\x00\x06",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "audio/midi",
+    ),
+    Pattern::new(
+        b"RIFF\x00\x00\x00\x00AVI ",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "video/avi",
+    ),
+    Pattern::new(
+        b"RIFF\x00\x00\x00\x00WAVE",
+        b"\xFF\xFF\xFF\xFF\x00\x00\x00\x00\xFF\xFF\xFF\xFF",
+        "audio/wave",
+    ),
+];
+
+/// See <https://mimesniff.spec.whatwg.org/#matching-an-archive-type-pattern>.
+const ARCHIVE_PATTERNS: &[Pattern] = &[
+    Pattern::new(b"\x1F\x8B\x08", b"\xFF\xFF\xFF", "application/x-gzip"),
+    Pattern::new(b"PK\x03\x04", b"\xFF\xFF\xFF\xFF", "application/zip"),
+    Pattern::new(
+        b"Rar!\x1A\x07\x00",
+        b"\xFF\xFF\xFF\xFF\xFF\xFF\xFF",
+        "application/x-rar-compressed",
+    ),
+];
+
+/// Returns `true` if `byte` is a [whitespace byte][spec].
+///
+/// [spec]: https://mimesniff.spec.whatwg.org/#whitespace-byte
+fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, 0x09 | 0x0A | 0x0C | 0x0D | 0x20)
+}
+
+/// Returns `true` if `byte` is a [binary data byte][spec].
+///
+/// [spec]: https://mimesniff.spec.whatwg.org/#binary-data-byte
+fn is_binary_data(byte: u8) -> bool {
+    matches!(byte, 0x00..=0x08 | 0x0B | 0x0E..=0x1A | 0x1C..=0x1F)
+}
+
+/// Returns `true` if `input` starts with one of the [`HTML_TAGS`], ignoring leading
+/// whitespace and ASCII case, followed by a tag-terminating byte.
+fn matches_html_tag(input: &[u8]) -> bool {
+    let start = input
+        .iter()
+        .position(|b| !is_whitespace(*b))
+        .unwrap_or(input.len());
+    let input = &input[start..];
+
+    HTML_TAGS.iter().any(|tag| {
+        input.len() > tag.len()
+            && input[..tag.len()].eq_ignore_ascii_case(tag)
+            && matches!(input[tag.len()], b' ' | b'>')
+    })
+}
+
+/// The [signature for MP4][spec].
+///
+/// [spec]: https://mimesniff.spec.whatwg.org/#signature-for-mp4
+fn matches_mp4(input: &[u8]) -> bool {
+    if input.len() < 12 {
+        return false;
+    }
+    let box_size = u32::from_be_bytes([input[0], input[1], input[2], input[3]]);
+    let Ok(box_size) = usize::try_from(box_size) else {
+        return false;
+    };
+    if input.len() < box_size || box_size % 4 != 0 {
+        return false;
+    }
+    if &input[4..8] != b"ftyp" {
+        return false;
+    }
+    if &input[8..11] == b"mp4" {
+        return true;
+    }
+
+    // Skip the minor version, then check each compatible brand.
+    input[16.min(box_size)..box_size]
+        .chunks_exact(4)
+        .any(|brand| &brand[..3] == b"mp4")
+}
+
+/// Determine the MIME type of a resource without an explicit one, from its first bytes,
+/// following the [rules for identifying an unknown MIME type][spec].
+///
+/// Scriptable types, i.e. HTML, XML and PDF, are only detected if `sniff_scriptable`
+/// is `true`. Resources that don't match any signature are identified as `text/plain`
+/// if they contain no binary data, and as `application/octet-stream` otherwise.
+///
+/// [spec]: https://mimesniff.spec.whatwg.org/#rules-for-identifying-an-unknown-mime-type
+#[must_use]
+pub fn sniff(bytes: &[u8], sniff_scriptable: bool) -> &'static str {
+    let header = &bytes[..bytes.len().min(RESOURCE_HEADER_LENGTH)];
+
+    if sniff_scriptable {
+        if matches_html_tag(header) {
+            return "text/html";
+        }
+        if let Some(pattern) = SCRIPTABLE_PATTERNS.iter().find(|p| p.matches(header)) {
+            return pattern.mime;
+        }
+    }
+
+    if let Some(pattern) = NON_SCRIPTABLE_PATTERNS
+        .iter()
+        .chain(IMAGE_PATTERNS)
+        .chain(AUDIO_VIDEO_PATTERNS)
+        .find(|p| p.matches(header))
+    {
+        return pattern.mime;
+    }
+    if matches_mp4(header) {
+        return "video/mp4";
+    }
+    if let Some(pattern) = ARCHIVE_PATTERNS.iter().find(|p| p.matches(header)) {
+        return pattern.mime;
+    }
+
+    if header.iter().copied().any(is_binary_data) {
+        "application/octet-stream"
+    } else {
+        "text/plain"
+    }
+}
@@ -7,10 +7,12 @@
 //! [spec]: https://fetch.spec.whatwg.org/
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/fetch
 
+use crate::fetch::abort::{abort_reason, abortable, follow_signal, is_signal_aborted};
 use crate::fetch::headers::JsHeaders;
 use crate::fetch::request::{JsRequest, RequestInit};
 use crate::fetch::response::JsResponse;
 use boa_engine::class::Class;
+use boa_engine::job::TaskPriority;
 use boa_engine::realm::Realm;
 use boa_engine::{
     Context, Finalize, JsData, JsError, JsObject, JsResult, JsString, JsValue, NativeObject, Trace,
@@ -21,7 +23,10 @@ use http::{HeaderName, HeaderValue, Request as HttpRequest, Request};
 use std::cell::RefCell;
 use std::rc::Rc;
 
+pub mod abort;
+pub mod body;
 pub mod headers;
+pub mod mime;
 pub mod request;
 pub mod response;
 pub mod tests;
@@ -88,6 +93,24 @@ fn get_fetcher<T: Fetcher>(context: &mut Context) -> JsResult<Rc<T>> {
     Ok(fetcher.0.clone())
 }
 
+/// The priority of the task settling a fetch: the priority of the options if they specify
+/// one, else the priority of the `Request` object, if any.
+fn fetch_priority(
+    resource: &Either<JsString, JsObject>,
+    options: Option<&RequestInit>,
+) -> JsResult<TaskPriority> {
+    if let Some(priority) = options.map(RequestInit::priority).transpose()?.flatten() {
+        return Ok(priority);
+    }
+
+    Ok(match resource {
+        Either::Left(_) => TaskPriority::default(),
+        Either::Right(request) => request
+            .downcast_ref::<JsRequest>()
+            .map_or_else(TaskPriority::default, |request| request.priority()),
+    })
+}
+
 /// The `fetch` function internals.
 async fn fetch_inner<T: Fetcher>(
     resource: Either<JsString, JsObject>,
@@ -98,6 +121,7 @@ async fn fetch_inner<T: Fetcher>(
 
     // The resource parsing is complicated, so we parse it in Rust here (instead of relying on
     // `TryFromJs` and friends).
+    let mut signal = None;
     let request: Request<Vec<u8>> = match resource {
         Either::Left(url) => {
             let url = url.to_std_string().map_err(JsError::from_rust)?;
@@ -117,27 +141,56 @@ async fn fetch_inner<T: Fetcher>(
                 return Err(js_error!(TypeError: "Request object is already in use"));
             };
 
+            signal = request_ref.data().signal().cloned();
             request_ref.data().inner().clone()
         }
     };
 
+    // The signal specified in the options replaces the signal of the request.
+    if let Some(options) = &options {
+        if let Some(init_signal) = options.signal()? {
+            signal = init_signal;
+        }
+    }
+
     let mut request = if let Some(options) = options {
-        options.into_request_builder(Some(request))?
+        options.into_request_builder(Some(request), &mut context.borrow_mut())?
     } else {
         request
     };
 
     // Add the `Accept-Language` which should be automatically included, unless specified.
+    // It is the user's preferred locale, if the host set one.
     if !request.headers().contains_key(
         "accept-language"
             .parse::<HeaderName>()
             .map_err(JsError::from_rust)?,
     ) {
-        let lang = HeaderValue::from_static("en-US");
+        let lang = match context.borrow().locale() {
+            Some(locale) => HeaderValue::from_str(&locale.to_std_string_escaped())
+                .map_err(JsError::from_rust)?,
+            None => HeaderValue::from_static("en-US"),
+        };
         request.headers_mut().append("Accept-Language", lang);
     }
 
-    let response = fetcher.fetch(JsRequest::from(request), context).await?;
+    let request = JsRequest::from(request);
+    let response = if let Some(signal) = signal {
+        // An already aborted signal rejects the fetch without ever reaching the fetcher.
+        if is_signal_aborted(&signal, &mut context.borrow_mut())? {
+            return Err(abort_reason(&signal, &mut context.borrow_mut()));
+        }
+
+        // Otherwise, the fetcher is handed a token to cancel the request once the
+        // signal aborts, and the fetch rejects with the signal's reason right away.
+        let (token, listener) = follow_signal(&signal, &mut context.borrow_mut())?;
+        let request = request.with_abort_token(token.clone());
+        let response = abortable(fetcher.fetch(request, context), &token, &signal, context).await;
+        listener.remove(&mut context.borrow_mut())?;
+        response?
+    } else {
+        fetcher.fetch(request, context).await?
+    };
     let result = Class::from_data(response, &mut context.borrow_mut())?;
     Ok(result.into())
 }
@@ -146,9 +199,10 @@ async fn fetch_inner<T: Fetcher>(
 #[boa_module]
 pub mod js_module {
     use crate::fetch::request::RequestInit;
-    use crate::fetch::{Fetcher, fetch_inner};
+    use crate::fetch::{Fetcher, fetch_inner, fetch_priority};
+    use boa_engine::job::{GenericJob, NativeAsyncJob};
     use boa_engine::object::builtins::JsPromise;
-    use boa_engine::{Context, JsObject, JsString};
+    use boa_engine::{Context, JsObject, JsString, JsValue};
     use either::Either;
 
     type JsHeaders = super::JsHeaders;
@@ -166,10 +220,36 @@ pub mod js_module {
         options: Option<RequestInit>,
         context: &mut Context,
     ) -> JsPromise {
-        JsPromise::from_async_fn(
-            async move |context| fetch_inner::<T>(resource, options, context).await,
-            context,
-        )
+        let priority = match fetch_priority(&resource, options.as_ref()) {
+            Ok(priority) => priority,
+            Err(err) => return JsPromise::reject(err, context),
+        };
+        let (promise, resolvers) = JsPromise::new_pending(context);
+
+        context.enqueue_job(
+            NativeAsyncJob::new(async move |context| {
+                let result = fetch_inner::<T>(resource, options, context).await;
+
+                // The promise is settled by a task in the lane of the priority of the fetch.
+                let context = &mut context.borrow_mut();
+                let realm = context.realm().clone();
+                let settle = GenericJob::new(
+                    move |context| match result {
+                        Ok(v) => resolvers.resolve.call(&JsValue::undefined(), &[v], context),
+                        Err(e) => {
+                            let e = e.to_opaque(context);
+                            resolvers.reject.call(&JsValue::undefined(), &[e], context)
+                        }
+                    },
+                    realm,
+                );
+                context.enqueue_job(settle.with_priority(priority).into());
+                Ok(JsValue::undefined())
+            })
+            .into(),
+        );
+
+        promise
     }
 }
 
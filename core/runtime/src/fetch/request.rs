@@ -4,11 +4,18 @@
 //!
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Request
 use super::HttpRequest;
+use super::abort::AbortToken;
+use super::body::{Body, BodyFormat, extract_body};
+use super::headers::is_forbidden_request_header;
+use boa_engine::job::TaskPriority;
+use boa_engine::object::builtins::JsPromise;
 use boa_engine::value::{Convert, TryFromJs};
 use boa_engine::{
-    Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
 };
 use either::Either;
+use http::header::CONTENT_TYPE;
+use http::{HeaderName, HeaderValue, Method};
 use std::collections::BTreeMap;
 use std::mem;
 
@@ -35,12 +42,52 @@ fn add_headers_to_builder<'a>(
             )
         })?;
 
+        // Requests are filled with the `request` guard, which silently drops forbidden
+        // request-headers.
+        if let (Ok(name), Ok(header_value)) = (
+            HeaderName::from_bytes(key.as_bytes()),
+            HeaderValue::from_str(&value),
+        ) {
+            if is_forbidden_request_header(&name, &header_value) {
+                continue;
+            }
+        }
+
         builder = builder.header(key, value);
     }
 
     Ok(builder)
 }
 
+/// Validate and [normalize][spec] a request method.
+///
+/// [spec]: https://fetch.spec.whatwg.org/#concept-method-normalize
+fn normalize_method(method: &JsString) -> JsResult<Method> {
+    let invalid = || {
+        js_error!(
+            TypeError: "Request constructor: {} is an invalid method",
+            method.to_std_string_escaped()
+        )
+    };
+    let method = method.to_std_string().map_err(|_| invalid())?;
+
+    // Forbidden methods cannot be used by scripts.
+    if ["CONNECT", "TRACE", "TRACK"]
+        .iter()
+        .any(|forbidden| method.eq_ignore_ascii_case(forbidden))
+    {
+        return Err(js_error!(
+            TypeError: "Request constructor: {} is a forbidden method",
+            method
+        ));
+    }
+
+    let normalized = ["DELETE", "GET", "HEAD", "OPTIONS", "POST", "PUT"]
+        .into_iter()
+        .find(|known| method.eq_ignore_ascii_case(known));
+    Method::from_bytes(normalized.unwrap_or(&method).as_bytes()).map_err(|_| invalid())
+}
+
 type VecOrMap<K, V> = Either<Vec<(K, V)>, BTreeMap<K, V>>;
 
 /// A [RequestInit][mdn] object. This is a JavaScript object (not a
@@ -53,17 +100,68 @@ pub struct RequestInit {
     body: Option<JsValue>,
     headers: Option<VecOrMap<JsString, Convert<JsString>>>,
     method: Option<Convert<JsString>>,
+    priority: Option<Convert<JsString>>,
+    signal: Option<JsValue>,
 }
 
 impl RequestInit {
+    /// Returns `true` if this specifies a non-null body.
+    fn has_body(&self) -> bool {
+        self.body
+            .as_ref()
+            .is_some_and(|body| !body.is_null_or_undefined())
+    }
+
+    /// Returns the `AbortSignal` specified by JavaScript, `Some(None)` if the signal is
+    /// explicitly `null`, or `None` if no signal is specified.
+    ///
+    /// # Errors
+    /// If the signal is neither an object nor `null`, an error is returned.
+    pub fn signal(&self) -> JsResult<Option<Option<JsObject>>> {
+        match &self.signal {
+            None => Ok(None),
+            Some(signal) if signal.is_undefined() => Ok(None),
+            Some(signal) if signal.is_null() => Ok(Some(None)),
+            Some(signal) => signal
+                .as_object()
+                .map(|signal| Some(Some(signal)))
+                .ok_or_else(
+                    || js_error!(TypeError: "Request constructor: signal is not an AbortSignal"),
+                ),
+        }
+    }
+
+    /// Returns the [priority][spec] specified by JavaScript, as the [`TaskPriority`] of the
+    /// task settling the fetch, or `None` if no priority is specified.
+    ///
+    /// # Errors
+    /// If the priority is not one of `"high"`, `"low"` or `"auto"`, an error is returned.
+    ///
+    /// [spec]: https://fetch.spec.whatwg.org/#dom-requestinit-priority
+    pub fn priority(&self) -> JsResult<Option<TaskPriority>> {
+        let Some(Convert(priority)) = &self.priority else {
+            return Ok(None);
+        };
+        match priority.to_std_string_escaped().as_str() {
+            "high" => Ok(Some(TaskPriority::UserBlocking)),
+            "low" => Ok(Some(TaskPriority::Background)),
+            "auto" => Ok(Some(TaskPriority::UserVisible)),
+            priority => Err(js_error!(TypeError: "{:?} is not a valid request priority", priority)),
+        }
+    }
+
     /// Create an [`http::request::Builder`] object and return both the
     /// body specified by JavaScript and the builder.
     ///
+    /// A string body sets the `Content-Type` header to `text/plain;charset=UTF-8`,
+    /// unless the headers already specify one.
+    ///
     /// # Errors
     /// If the body is not a valid type, an error is returned.
     pub fn into_request_builder(
         mut self,
         request: Option<HttpRequest<Vec<u8>>>,
+        context: &mut Context,
     ) -> JsResult<HttpRequest<Vec<u8>>> {
         let mut builder = HttpRequest::builder();
         if let Some(r) = request {
@@ -90,24 +188,23 @@ impl RequestInit {
         }
 
         if let Some(Convert(ref method)) = self.method.take() {
-            builder = builder.method(method.to_std_string().map_err(
-                |_| js_error!(TypeError: "Request constructor: {} is an invalid method", method.to_std_string_escaped()),
-            )?.as_str());
+            builder = builder.method(normalize_method(method)?);
         }
 
         let mut request_body = None;
-        if let Some(body) = &self.body {
-            // TODO: add more support types.
-            if let Some(body) = body.as_string() {
-                let body = body.to_std_string().map_err(
-                    |_| js_error!(TypeError: "Request constructor: body is not a valid string"),
-                )?;
-                request_body = Some(body.into_bytes());
-            } else {
-                return Err(
-                    js_error!(TypeError: "Request constructor: body is not a supported type"),
-                );
+        if let Some(body) = self
+            .body
+            .as_ref()
+            .filter(|body| !body.is_null_or_undefined())
+        {
+            let (bytes, content_type) = extract_body(body, context)?;
+            let has_content_type = builder
+                .headers_ref()
+                .is_some_and(|headers| headers.contains_key(CONTENT_TYPE));
+            if let Some(content_type) = content_type.filter(|_| !has_content_type) {
+                builder = builder.header(CONTENT_TYPE, content_type);
             }
+            request_body = Some(bytes);
         }
 
         builder
@@ -125,6 +222,17 @@ impl RequestInit {
 pub struct JsRequest {
     #[unsafe_ignore_trace]
     inner: HttpRequest<Vec<u8>>,
+
+    #[unsafe_ignore_trace]
+    body: Body,
+
+    signal: Option<JsObject>,
+
+    #[unsafe_ignore_trace]
+    priority: TaskPriority,
+
+    #[unsafe_ignore_trace]
+    abort_token: Option<AbortToken>,
 }
 
 impl JsRequest {
@@ -143,15 +251,47 @@ impl JsRequest {
         self.inner.uri()
     }
 
+    /// Get the `AbortSignal` of the request, if any.
+    pub fn signal(&self) -> Option<&JsObject> {
+        self.signal.as_ref()
+    }
+
+    /// Get the priority of the task settling a fetch of the request.
+    pub fn priority(&self) -> TaskPriority {
+        self.priority
+    }
+
+    /// Get the token aborted when the signal of the fetch is, if the fetch has a signal.
+    ///
+    /// [`Fetcher`][super::Fetcher] implementations should use it to cancel the in-flight
+    /// request once aborted; see [`AbortToken::on_abort`] and [`AbortToken::aborted`].
+    pub fn abort_token(&self) -> Option<&AbortToken> {
+        self.abort_token.as_ref()
+    }
+
+    /// Attach the token aborted when the signal of the fetch is.
+    pub(crate) fn with_abort_token(mut self, abort_token: AbortToken) -> Self {
+        self.abort_token = Some(abort_token);
+        self
+    }
+
     /// Create a [`JsRequest`] instance from JavaScript arguments, similar to
     /// calling its constructor in JavaScript.
     ///
+    /// When `input` is a request and `options` doesn't specify a body, the body
+    /// of `input` is carried over to the new request.
+    ///
     /// # Errors
-    /// If the URI is invalid, an error is returned.
+    /// If the URI is invalid, if the body of `input` was already used, or if a
+    /// `GET` or `HEAD` request would have a body, an error is returned.
     pub fn create_from_js(
         input: Either<JsString, JsRequest>,
         options: Option<RequestInit>,
+        context: &mut Context,
     ) -> JsResult<Self> {
+        let mut input_body = Body::null();
+        let mut signal = None;
+        let mut priority = TaskPriority::default();
         let request = match input {
             Either::Left(uri) => {
                 let uri = http::Uri::try_from(
@@ -164,21 +304,75 @@ impl JsRequest {
                     .body(Vec::<u8>::new())
                     .map_err(|_| js_error!(Error: "Cannot construct request"))?
             }
-            Either::Right(r) => r.into_inner(),
+            Either::Right(r) => {
+                if r.body.is_used() {
+                    return Err(
+                        js_error!(TypeError: "Request constructor: input request body has already been used"),
+                    );
+                }
+                input_body = r.body.clone();
+                signal = r.signal.clone();
+                priority = r.priority;
+                r.into_inner()
+            }
         };
 
-        if let Some(options) = options {
-            let inner = options.into_request_builder(Some(request))?;
-            Ok(Self { inner })
-        } else {
-            Ok(Self { inner: request })
+        if let Some(options) = &options {
+            if let Some(init_signal) = options.signal()? {
+                signal = init_signal;
+            }
+            if let Some(init_priority) = options.priority()? {
+                priority = init_priority;
+            }
         }
+
+        let has_init_body = options.as_ref().is_some_and(RequestInit::has_body);
+
+        let (inner, body) = match options {
+            Some(options) if has_init_body => {
+                let inner = options.into_request_builder(Some(request), context)?;
+                let body = Body::new(inner.body().clone());
+                (inner, body)
+            }
+            Some(options) => {
+                let mut inner = options.into_request_builder(Some(request), context)?;
+                *inner.body_mut() = input_body.bytes().to_vec();
+                (inner, input_body)
+            }
+            None => (request, input_body),
+        };
+
+        if !body.is_null() && matches!(*inner.method(), Method::GET | Method::HEAD) {
+            return Err(
+                js_error!(TypeError: "Request constructor: a GET or HEAD request cannot have a body"),
+            );
+        }
+
+        Ok(Self {
+            inner,
+            body,
+            signal,
+            priority,
+            abort_token: None,
+        })
     }
 }
 
 impl From<HttpRequest<Vec<u8>>> for JsRequest {
     fn from(inner: HttpRequest<Vec<u8>>) -> Self {
-        Self { inner }
+        // Requests without any content have a null body.
+        let body = if inner.body().is_empty() {
+            Body::null()
+        } else {
+            Body::new(inner.body().clone())
+        };
+        Self {
+            inner,
+            body,
+            signal: None,
+            priority: TaskPriority::default(),
+            abort_token: None,
+        }
     }
 }
 
@@ -192,19 +386,59 @@ impl JsRequest {
     pub fn constructor(
         input: Either<JsString, JsObject>,
         options: Option<RequestInit>,
+        context: &mut Context,
     ) -> JsResult<Self> {
         // Need to use a match as `Either::map_right` does not have an equivalent
         // `Either::map_right_ok`.
-        let input = match input {
+        let (input, source) = match input {
             Either::Right(r) => {
                 if let Ok(request) = r.clone().downcast::<JsRequest>() {
-                    Either::Right(request.borrow().data().clone())
+                    (
+                        Either::Right(request.borrow().data().clone()),
+                        Some(request),
+                    )
                 } else {
                     return Err(js_error!(TypeError: "invalid input argument"));
                 }
             }
-            Either::Left(i) => Either::Left(i),
+            Either::Left(i) => (Either::Left(i), None),
         };
-        JsRequest::create_from_js(input, options)
+        let has_init_body = options.as_ref().is_some_and(RequestInit::has_body);
+
+        let request = JsRequest::create_from_js(input, options, context)?;
+
+        // The body of the input request is transferred to the new request, which
+        // leaves the input request unusable.
+        if let Some(source) = source.filter(|_| !has_init_body) {
+            source.borrow_mut().data_mut().body.disturb();
+        }
+
+        Ok(request)
+    }
+
+    #[boa(getter)]
+    fn method(&self) -> JsString {
+        JsString::from(self.inner.method().as_str())
+    }
+
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.body.is_used()
+    }
+
+    fn array_buffer(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::ArrayBuffer, context)
+    }
+
+    fn bytes(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Bytes, context)
+    }
+
+    fn text(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Text, context)
+    }
+
+    fn json(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Json, context)
     }
 }
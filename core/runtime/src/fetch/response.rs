@@ -5,12 +5,13 @@
 //!
 //! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Response
 
-use crate::fetch::headers::JsHeaders;
-use boa_engine::object::builtins::{JsPromise, JsUint8Array};
-use boa_engine::value::{TryFromJs, TryIntoJs};
+use crate::fetch::body::{Body, BodyFormat, extract_body};
+use crate::fetch::headers::{HeadersGuard, JsHeaders};
+use crate::fetch::mime;
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::value::{Convert, TryFromJs, TryIntoJs};
 use boa_engine::{
-    Context, JsData, JsNativeError, JsResult, JsString, JsValue, boa_class, js_error, js_str,
-    js_string,
+    Context, JsData, JsResult, JsString, JsValue, boa_class, js_error, js_str, js_string,
 };
 use boa_gc::{Finalize, Trace};
 use http::StatusCode;
@@ -107,7 +108,7 @@ pub struct JsResponse {
     headers: JsHeaders,
 
     #[unsafe_ignore_trace]
-    body: Rc<Vec<u8>>,
+    body: Body,
 }
 
 impl JsResponse {
@@ -116,8 +117,8 @@ impl JsResponse {
     pub fn basic(url: JsString, inner: http::Response<Vec<u8>>) -> Self {
         let (parts, body) = inner.into_parts();
         let status = Some(parts.status);
-        let headers = JsHeaders::from_http(parts.headers);
-        let body = Rc::new(body);
+        let headers = JsHeaders::from_http(parts.headers).with_guard(HeadersGuard::Immutable);
+        let body = Body::new(body);
 
         Self {
             url,
@@ -135,15 +136,30 @@ impl JsResponse {
             url: js_string!(""),
             r#type: ResponseType::Error,
             status: None,
-            headers: JsHeaders::default(),
-            body: Rc::new(Vec::new()),
+            headers: JsHeaders::default().with_guard(HeadersGuard::Immutable),
+            body: Body::null(),
         }
     }
 
     /// Return a copy of the body.
     #[must_use]
     pub fn body(&self) -> Rc<Vec<u8>> {
-        self.body.clone()
+        self.body.bytes()
+    }
+
+    /// Return the MIME type of the response: the value of its `Content-Type` header, or
+    /// the type [sniffed][crate::fetch::mime::sniff] from its body if it has none.
+    /// Scriptable types are never sniffed.
+    #[must_use]
+    pub fn mime_type(&self) -> String {
+        self.headers
+            .to_http()
+            .get(http::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .map_or_else(
+                || mime::sniff(&self.body.bytes(), false).to_owned(),
+                str::to_owned,
+            )
     }
 }
 
@@ -166,7 +182,23 @@ impl JsResponse {
     }
 
     #[boa(constructor)]
-    fn constructor(_body: Option<JsValue>, options: JsResponseOptions) -> Self {
+    fn constructor(
+        body: Option<JsValue>,
+        options: JsResponseOptions,
+        context: &mut Context,
+    ) -> JsResult<Self> {
+        let body = match body {
+            Some(body) if !body.is_null_or_undefined() => Some(extract_body(&body, context)?),
+            _ => None,
+        };
+
+        // Responses with a null body status cannot have a body.
+        if body.is_some() && matches!(options.status, Some(101 | 103 | 204 | 205 | 304)) {
+            return Err(js_error!(
+                TypeError: "Response constructor: a response with a null body status cannot have a body"
+            ));
+        }
+
         let mut response = http::Response::new(Vec::new());
 
         // Set status if provided
@@ -181,7 +213,35 @@ impl JsResponse {
         //     // StatusCode doesn't allow custom status text in http crate
         // }
 
-        Self::basic(js_string!(""), response)
+        let mut this = Self::basic(js_string!(""), response);
+
+        // Scripts can't forge `Set-Cookie` headers on the responses they create.
+        this.headers = JsHeaders::default().with_guard(HeadersGuard::Response);
+        if let Some(headers) = options.headers {
+            for (key, value) in &headers.to_http() {
+                this.headers.append(
+                    Convert::from(key.as_str().to_owned()),
+                    Convert::from(value.to_str().unwrap_or_default().to_owned()),
+                )?;
+            }
+        }
+
+        this.body = match body {
+            Some((bytes, content_type)) => {
+                let has_content_type =
+                    this.headers.has(Convert::from("content-type".to_owned()))?;
+                if let Some(content_type) = content_type.filter(|_| !has_content_type) {
+                    this.headers.append(
+                        Convert::from("content-type".to_owned()),
+                        Convert::from(content_type.to_owned()),
+                    )?;
+                }
+                Body::new(bytes)
+            }
+            None => Body::null(),
+        };
+
+        Ok(this)
     }
 
     #[boa(getter)]
@@ -221,39 +281,24 @@ impl JsResponse {
         self.url.clone()
     }
 
-    fn bytes(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |context| {
-                JsUint8Array::from_iter(body.iter().copied(), &mut context.borrow_mut())
-                    .map(Into::into)
-            },
-            context,
-        )
+    #[boa(getter)]
+    fn body_used(&self) -> bool {
+        self.body.is_used()
+    }
+
+    fn array_buffer(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::ArrayBuffer, context)
+    }
+
+    fn bytes(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Bytes, context)
     }
 
-    fn text(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |_| {
-                let body = String::from_utf8_lossy(body.as_ref());
-                Ok(JsString::from(body).into())
-            },
-            context,
-        )
+    fn text(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Text, context)
     }
 
-    fn json(&self, context: &mut Context) -> JsPromise {
-        let body = self.body.clone();
-        JsPromise::from_async_fn(
-            async move |context| {
-                let json_string = String::from_utf8_lossy(body.as_ref());
-                let json = serde_json::from_str::<serde_json::Value>(&json_string)
-                    .map_err(|e| JsNativeError::syntax().with_message(e.to_string()))?;
-
-                JsValue::from_json(&json, &mut context.borrow_mut())
-            },
-            context,
-        )
+    fn json(&mut self, context: &mut Context) -> JsPromise {
+        self.body.consume(BodyFormat::Json, context)
     }
 }
@@ -1,9 +1,11 @@
 use crate::fetch::request::JsRequest;
 use crate::fetch::response::JsResponse;
-use crate::test::{TestAction, run_test_actions};
-use boa_engine::{Context, Finalize, JsData, JsError, JsResult, JsString, Trace, js_error, js_str};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsResult, JsString, Trace, js_error, js_str, js_string,
+};
 use http::Response;
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::collections::BTreeMap;
 use std::rc::Rc;
 use url::Url;
@@ -88,3 +90,160 @@ fn custom_header() {
         TestAction::inspect_context(await_response),
     ]);
 }
+
+#[test]
+fn accept_language_from_locale() {
+    let context = &mut Context::builder()
+        .locale(js_string!("fr-CA"))
+        .build()
+        .unwrap();
+    crate::register(
+        crate::extensions::ConsoleExtension::default(),
+        None,
+        context,
+    )
+    .unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::inspect_context(register),
+            TestAction::run(
+                r#"
+                    globalThis.response = (async () => {
+                        const response = await fetch("http://unit.test/headers?header=accept-language");
+                        assertEq(response.headers.get("x-headers"), "fr-CA");
+                    })();
+                "#,
+            ),
+            TestAction::inspect_context(await_response),
+        ],
+        context,
+    );
+}
+
+/// A fetcher whose requests only complete once aborted, recording the cancellations
+/// it was notified of.
+#[derive(Debug, Clone, Default, Trace, Finalize, JsData)]
+struct PendingFetcher {
+    #[unsafe_ignore_trace]
+    cancelled: Rc<Cell<u32>>,
+}
+
+impl crate::fetch::Fetcher for PendingFetcher {
+    async fn fetch(
+        self: Rc<Self>,
+        request: JsRequest,
+        _context: &RefCell<&mut Context>,
+    ) -> JsResult<JsResponse> {
+        let Some(token) = request.abort_token().cloned() else {
+            return Err(js_error!("Request has no abort token."));
+        };
+
+        let cancelled = self.cancelled.clone();
+        token.on_abort(move || cancelled.set(cancelled.get() + 1));
+        token.aborted().await;
+        Err(js_error!("Request cancelled by the transport."))
+    }
+}
+
+const FAKE_SIGNAL: &str = r#"
+    function createSignal() {
+        const listeners = [];
+        return {
+            aborted: false,
+            reason: undefined,
+            get listenerCount() {
+                return listeners.length;
+            },
+            addEventListener(type, listener) {
+                if (type === "abort") listeners.push(listener);
+            },
+            removeEventListener(type, listener) {
+                const index = listeners.indexOf(listener);
+                if (type === "abort" && index !== -1) listeners.splice(index, 1);
+            },
+            abort(reason) {
+                this.aborted = true;
+                this.reason = reason;
+                listeners.forEach((listener) => listener());
+            },
+        };
+    }
+"#;
+
+#[test]
+fn abort_in_flight_fetch() {
+    let fetcher = PendingFetcher::default();
+    let cancelled = fetcher.cancelled.clone();
+
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(move |ctx| {
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(FAKE_SIGNAL),
+        TestAction::run(
+            r#"
+                const signal = createSignal();
+                const request = new Request("http://unit.test/pending", { signal });
+                globalThis.response = fetch(request).then(
+                    () => { throw new Error("the fetch should be aborted"); },
+                    (reason) => assertEq(reason, "stopped"),
+                );
+                setTimeout(() => signal.abort("stopped"), 0);
+            "#,
+        ),
+        TestAction::inspect_context(await_response),
+        TestAction::run("assertEq(signal.listenerCount, 0)"),
+    ]);
+
+    assert_eq!(cancelled.get(), 1);
+}
+
+#[test]
+fn signal_listener_removed_after_fetch() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(register),
+        TestAction::run(FAKE_SIGNAL),
+        TestAction::run(
+            r#"
+                const signal = createSignal();
+                globalThis.response = (async () => {
+                    await fetch("http://unit.test/headers?header=test", { signal });
+                    assertEq(signal.listenerCount, 0);
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(await_response),
+    ]);
+}
+
+#[test]
+fn abort_before_fetch() {
+    let fetcher = PendingFetcher::default();
+    let cancelled = fetcher.cancelled.clone();
+
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(move |ctx| {
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(FAKE_SIGNAL),
+        TestAction::run(
+            r#"
+                const signal = createSignal();
+                signal.abort("too late");
+                globalThis.response = fetch("http://unit.test/pending", { signal }).then(
+                    () => { throw new Error("the fetch should be aborted"); },
+                    (reason) => assertEq(reason, "too late"),
+                );
+            "#,
+        ),
+        TestAction::inspect_context(await_response),
+    ]);
+
+    // The fetcher was never reached.
+    assert_eq!(cancelled.get(), 0);
+}
@@ -0,0 +1,133 @@
+use super::TestFetcher;
+use crate::fetch::headers::{HeadersGuard, JsHeaders};
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+use boa_engine::value::Convert;
+use http::{Response, Uri};
+
+fn append(headers: &mut JsHeaders, key: &str, value: &str) -> bool {
+    headers
+        .append(
+            Convert::from(key.to_owned()),
+            Convert::from(value.to_owned()),
+        )
+        .is_ok()
+}
+
+#[test]
+fn headers_guards() {
+    let mut headers = JsHeaders::default().with_guard(HeadersGuard::Request);
+    assert!(append(&mut headers, "x-custom", "1"));
+    assert!(append(&mut headers, "cookie", "a=b"));
+    assert!(append(&mut headers, "sec-fetch-mode", "cors"));
+    assert!(append(&mut headers, "x-http-method-override", "TRACE"));
+    assert!(append(&mut headers, "x-http-method-override", "PATCH"));
+    let map = headers.to_http();
+    assert_eq!(map.get("x-custom").unwrap(), "1");
+    assert!(!map.contains_key("cookie"));
+    assert!(!map.contains_key("sec-fetch-mode"));
+    assert_eq!(map.get_all("x-http-method-override").iter().count(), 1);
+
+    let mut headers = JsHeaders::default().with_guard(HeadersGuard::RequestNoCors);
+    assert!(append(&mut headers, "accept-language", "en-US"));
+    assert!(append(
+        &mut headers,
+        "content-type",
+        "text/plain; charset=utf-8"
+    ));
+    assert!(append(&mut headers, "content-type", "application/json"));
+    assert!(append(&mut headers, "x-custom", "1"));
+    let map = headers.to_http();
+    assert_eq!(map.get("accept-language").unwrap(), "en-US");
+    assert_eq!(map.get_all("content-type").iter().count(), 1);
+    assert!(!map.contains_key("x-custom"));
+
+    let mut headers = JsHeaders::default().with_guard(HeadersGuard::Response);
+    assert!(append(&mut headers, "set-cookie", "a=b"));
+    assert!(append(&mut headers, "x-custom", "1"));
+    let map = headers.to_http();
+    assert!(!map.contains_key("set-cookie"));
+    assert_eq!(map.get("x-custom").unwrap(), "1");
+
+    let mut headers = JsHeaders::default().with_guard(HeadersGuard::Immutable);
+    assert!(!append(&mut headers, "x-custom", "1"));
+}
+
+#[test]
+fn response_headers_guards() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            let mut fetcher = TestFetcher::default();
+            fetcher.add_response(
+                Uri::from_static("http://unit.test"),
+                Response::builder()
+                    .header("Set-Cookie", "a=1")
+                    .header("Set-Cookie", "b=2")
+                    .body(Vec::new())
+                    .unwrap(),
+            );
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                const created = new Response("", {
+                    headers: { "Set-Cookie": "forged=1", "X-Custom": "1" },
+                });
+                assertEq(created.headers.get("set-cookie"), null);
+                assertEq(created.headers.get("x-custom"), "1");
+                created.headers.append("set-cookie", "forged=2");
+                assertEq(created.headers.getSetCookie().length, 0);
+
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    assertArrayEqual(response.headers.getSetCookie(), ["a=1", "b=2"]);
+                    try {
+                        response.headers.set("x-custom", "1");
+                        throw "AssertionError: immutable headers were modified";
+                    } catch (e) {
+                        assert(e instanceof TypeError);
+                    }
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn headers_iteration_order() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            crate::fetch::register(TestFetcher::default(), None, ctx)
+                .expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                const headers = new Headers([
+                    ["x-b", "2"],
+                    ["X-A", "1"],
+                    ["set-cookie", "c=1"],
+                    ["x-b", "3"],
+                    ["set-cookie", "d=2"],
+                ]);
+
+                assertEq(
+                    JSON.stringify(headers.entries()),
+                    '[["set-cookie","c=1"],["set-cookie","d=2"],["x-a","1"],["x-b","2, 3"]]',
+                );
+                assertArrayEqual(headers.keys(), ["set-cookie", "set-cookie", "x-a", "x-b"]);
+                assertArrayEqual(headers.values(), ["c=1", "d=2", "1", "2, 3"]);
+                assertEq(headers.get("x-b"), "2, 3");
+
+                const seen = [];
+                headers.forEach((value, key) => seen.push(`${key}=${value}`));
+                assertArrayEqual(seen, ["set-cookie=c=1", "set-cookie=d=2", "x-a=1", "x-b=2, 3"]);
+            "#,
+        ),
+    ]);
+}
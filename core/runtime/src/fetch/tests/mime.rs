@@ -0,0 +1,96 @@
+use super::TestFetcher;
+use crate::fetch::mime::sniff;
+use crate::fetch::response::JsResponse;
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_str;
+use http::{Response, Uri};
+
+#[test]
+fn sniff_signatures() {
+    let cases: &[(&[u8], &str)] = &[
+        (b"\x89PNG\r\n\x1A\n\x00\x00\x00\x0DIHDR", "image/png"),
+        (b"GIF89a\x01\x00\x01\x00", "image/gif"),
+        (b"\xFF\xD8\xFF\xE0", "image/jpeg"),
+        (b"RIFF\x24\x00\x00\x00WEBPVP8 ", "image/webp"),
+        (b"RIFF\x24\x00\x00\x00WAVEfmt ", "audio/wave"),
+        (b"ID3\x04\x00", "audio/mpeg"),
+        (
+            b"\x00\x00\x00\x18ftypisom\x00\x00\x02\x00isommp41",
+            "video/mp4",
+        ),
+        (b"PK\x03\x04\x14\x00", "application/zip"),
+        (b"\x1F\x8B\x08\x00", "application/x-gzip"),
+        (b"%!PS-Adobe-3.0", "application/postscript"),
+        (b"\xEF\xBB\xBFhello", "text/plain"),
+        (b"hello world", "text/plain"),
+        (b"", "text/plain"),
+        (b"hello\x00world", "application/octet-stream"),
+    ];
+
+    for (bytes, expected) in cases {
+        assert_eq!(sniff(bytes, false), *expected, "sniffing {bytes:?}");
+    }
+}
+
+#[test]
+fn sniff_scriptable() {
+    let cases: &[(&[u8], &str)] = &[
+        (b"  \n<!doctype html>", "text/html"),
+        (b"<html lang=\"en\">", "text/html"),
+        (b"<p>text</p>", "text/html"),
+        (b"<?xml version=\"1.0\"?>", "text/xml"),
+        (b"%PDF-1.7", "application/pdf"),
+    ];
+
+    for (bytes, expected) in cases {
+        assert_eq!(sniff(bytes, true), *expected, "sniffing {bytes:?}");
+        assert_eq!(sniff(bytes, false), "text/plain", "sniffing {bytes:?}");
+    }
+
+    // A tag must be followed by a tag-terminating byte.
+    assert_eq!(sniff(b"<pre>", true), "text/plain");
+}
+
+#[test]
+fn response_mime_type() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            let mut fetcher = TestFetcher::default();
+            fetcher.add_response(
+                Uri::from_static("http://unit.test/image"),
+                Response::new(b"GIF87a\x01\x00\x01\x00".to_vec()),
+            );
+            fetcher.add_response(
+                Uri::from_static("http://unit.test/typed"),
+                Response::builder()
+                    .header("content-type", "image/png")
+                    .body(b"GIF87a".to_vec())
+                    .unwrap(),
+            );
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.responses = Promise.all([
+                    fetch("http://unit.test/image"),
+                    fetch("http://unit.test/typed"),
+                ]);
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let responses = ctx.global_object().get(js_str!("responses"), ctx).unwrap();
+            let responses = responses.as_promise().unwrap().await_blocking(ctx).unwrap();
+            let responses = responses.as_object().unwrap();
+
+            let mime_types = (0..2)
+                .map(|i| {
+                    let response = responses.get(i, ctx).unwrap();
+                    let response = response.as_object().unwrap();
+                    response.downcast_ref::<JsResponse>().unwrap().mime_type()
+                })
+                .collect::<Vec<_>>();
+            assert_eq!(mime_types, ["image/gif", "image/png"]);
+        }),
+    ]);
+}
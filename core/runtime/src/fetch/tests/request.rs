@@ -2,7 +2,7 @@ use super::TestFetcher;
 use crate::fetch::request::JsRequest;
 use crate::fetch::response::JsResponse;
 use crate::test::{TestAction, run_test_actions};
-use boa_engine::{js_str, js_string};
+use boa_engine::{Context, JsString, js_str, js_string};
 use either::Either;
 use http::{Response, Uri};
 
@@ -39,11 +39,158 @@ fn request_constructor() {
                 "Hello World".as_bytes()
             );
         }),
-        TestAction::inspect_context(|_ctx| {
-            let request =
-                JsRequest::create_from_js(Either::Left(js_string!("http://example.com")), None)
-                    .unwrap();
+        TestAction::inspect_context(|ctx| {
+            let request = JsRequest::create_from_js(
+                Either::Left(js_string!("http://example.com")),
+                None,
+                ctx,
+            )
+            .unwrap();
             assert_eq!(request.uri().to_string(), "http://example.com/");
         }),
     ]);
 }
+
+#[test]
+fn request_from_request() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            crate::fetch::register(TestFetcher::default(), None, ctx)
+                .expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.result = (async () => {
+                    const original = new Request("http://unit.test", { method: "post", body: "payload" });
+                    assertEq(original.method, "POST");
+
+                    const rewritten = new Request(original, { headers: { "x-rewritten": "1" } });
+                    assertEq(rewritten.method, "POST");
+                    assert(!rewritten.bodyUsed);
+                    assert(original.bodyUsed);
+                    assertThrows(() => new Request(original));
+
+                    const replaced = new Request(rewritten, { body: "other" });
+                    assert(!rewritten.bodyUsed);
+
+                    assertThrows(() => new Request("http://unit.test", { body: "payload" }));
+                    assertThrows(() => new Request(replaced, { method: "GET" }));
+                    assertThrows(() => new Request("http://unit.test", { method: "TRACE" }));
+
+                    const head = new Request("http://unit.test", { method: "head", body: null });
+                    assertEq(head.method, "HEAD");
+
+                    assertEq(await rewritten.text(), "payload");
+                    assertEq(await replaced.text(), "other");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+            result.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn request_bodies() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            crate::fetch::register(TestFetcher::default(), None, ctx)
+                .expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                globalThis.bytes = new Request("http://unit.test", {
+                    method: "POST",
+                    body: new Uint8Array([0, 104, 105, 0]).subarray(1, 3),
+                });
+                globalThis.text = new Request("http://unit.test", { method: "POST", body: "hi" });
+                globalThis.typed = new Request("http://unit.test", {
+                    method: "POST",
+                    headers: { "content-type": "application/json" },
+                    body: "{}",
+                });
+                globalThis.lone = new Request("http://unit.test", { method: "POST", body: "a\uD800b" });
+                assertThrows(() => new Request("http://unit.test", { method: "POST", body: {} }));
+
+                globalThis.result = (async () => {
+                    assertEq(await bytes.text(), "hi");
+                    assertEq(await lone.text(), "a\uFFFDb");
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let content_type = |name: &str, ctx: &mut Context| {
+                let request = ctx.global_object().get(JsString::from(name), ctx).unwrap();
+                let request = request.as_object().unwrap();
+                let request = request.downcast_ref::<JsRequest>().unwrap();
+                request
+                    .inner()
+                    .headers()
+                    .get("content-type")
+                    .map(|value| value.to_str().unwrap().to_owned())
+            };
+            assert_eq!(content_type("bytes", ctx), None);
+            assert_eq!(
+                content_type("text", ctx).as_deref(),
+                Some("text/plain;charset=UTF-8")
+            );
+            assert_eq!(
+                content_type("typed", ctx).as_deref(),
+                Some("application/json")
+            );
+
+            let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+            result.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
+#[test]
+fn request_priority() {
+    use boa_engine::job::TaskPriority;
+
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            let mut fetcher = TestFetcher::default();
+            fetcher.add_response(
+                Uri::from_static("http://unit.test"),
+                Response::new(Vec::new()),
+            );
+            crate::fetch::register(fetcher, None, ctx).expect("failed to register fetch");
+        }),
+        TestAction::run(
+            r#"
+                const high = new Request("http://unit.test", { priority: "high" });
+                assertThrows(() => new Request("http://unit.test", { priority: "urgent" }));
+
+                globalThis.result = (async () => {
+                    await fetch("http://unit.test");
+                    await fetch("http://unit.test", { priority: "low" });
+                    await fetch(high);
+                    await fetch(high, { priority: "auto" });
+                    try {
+                        await fetch("http://unit.test", { priority: "urgent" });
+                        throw new Error("fetch did not reject");
+                    } catch (e) {
+                        assert(e instanceof TypeError);
+                    }
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let result = ctx.global_object().get(js_str!("result"), ctx).unwrap();
+            result.as_promise().unwrap().await_blocking(ctx).unwrap();
+
+            // Each fetch is settled by a task in the lane of its priority.
+            let statistics = ctx.job_statistics().unwrap();
+            assert_eq!(statistics.lane(TaskPriority::UserBlocking).completed, 1);
+            assert_eq!(statistics.lane(TaskPriority::UserVisible).completed, 2);
+            assert_eq!(statistics.lane(TaskPriority::Background).completed, 1);
+        }),
+    ]);
+}
@@ -59,6 +59,42 @@ fn response_text() {
     ]);
 }
 
+#[test]
+fn response_text_strips_bom() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            register(
+                &[
+                    (
+                        "http://unit.test/text",
+                        Response::new(b"\xEF\xBB\xBFHello".to_vec()),
+                    ),
+                    (
+                        "http://unit.test/json",
+                        Response::new(b"\xEF\xBB\xBF[1]".to_vec()),
+                    ),
+                ],
+                ctx,
+            );
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const text = await (await fetch("http://unit.test/text")).text();
+                    assertEq(text, "Hello");
+                    const json = await (await fetch("http://unit.test/json")).json();
+                    assertEq(json[0], 1);
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
+
 #[test]
 fn response_json() {
     run_test_actions([
@@ -155,3 +191,51 @@ fn response_getter() {
         }),
     ]);
 }
+
+#[test]
+fn response_body_one_shot() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|ctx| {
+            register(
+                &[("http://unit.test", Response::new(b"Hello World".to_vec()))],
+                ctx,
+            );
+        }),
+        TestAction::run(
+            r#"
+                globalThis.response = (async () => {
+                    const response = await fetch("http://unit.test");
+                    assert(!response.bodyUsed);
+                    assertEq(await response.text(), "Hello World");
+                    assert(response.bodyUsed);
+
+                    let error;
+                    try {
+                        await response.arrayBuffer();
+                    } catch (e) {
+                        error = e;
+                    }
+                    assert(error instanceof TypeError);
+
+                    const constructed = new Response(new Uint8Array([1, 2, 3]));
+                    const buffer = await constructed.arrayBuffer();
+                    assertArrayEqual(Array.from(new Uint8Array(buffer)), [1, 2, 3]);
+
+                    const text = new Response("abc");
+                    assertEq(text.headers.get("content-type"), "text/plain;charset=UTF-8");
+
+                    const empty = Response.error();
+                    assertEq(await empty.text(), "");
+                    assert(!empty.bodyUsed);
+
+                    assertThrows(() => new Response("abc", { status: 204 }));
+                })();
+            "#,
+        ),
+        TestAction::inspect_context(|ctx| {
+            let response = ctx.global_object().get(js_str!("response"), ctx).unwrap();
+            response.as_promise().unwrap().await_blocking(ctx).unwrap();
+        }),
+    ]);
+}
@@ -0,0 +1,82 @@
+//! Module containing the registration of custom platform interfaces.
+//!
+//! Crates implementing their own WHATWG-style APIs on top of the runtime define their
+//! interfaces as [`Class`]es, then bind them with [`register_platform_interface`], which
+//! follows the same conventions as the interfaces provided by this crate: the constructor
+//! is stored in the realm's class map, bound on its global object, and instances get the
+//! interface name as their `@@toStringTag`.
+//!
+//! See <https://webidl.spec.whatwg.org/#es-interfaces>.
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::class::{Class, ClassBuilder};
+use boa_engine::context::intrinsics::StandardConstructor;
+use boa_engine::property::{Attribute, PropertyDescriptor};
+use boa_engine::realm::Realm;
+use boa_engine::{Context, JsNativeError, JsResult, JsString, JsSymbol};
+
+/// Register the platform interface `C` in the realm or context, returning its
+/// constructor and prototype.
+///
+/// The interface object is bound on the global object as a writable, configurable and
+/// non-enumerable property, regardless of [`Class::ATTRIBUTES`], as done for interface
+/// objects. Unless the class defines its own, a `@@toStringTag` property holding the
+/// interface name is added to the prototype. Once registered, the constructor can be
+/// retrieved with [`Realm::get_class`].
+///
+/// # Errors
+/// Returns an error if `C` is already registered in the realm, or if the class cannot
+/// be initialized or bound on the global object.
+pub fn register_platform_interface<C: Class>(
+    realm: Option<Realm>,
+    context: &mut Context,
+) -> JsResult<StandardConstructor> {
+    let Some(realm) = realm else {
+        return register_in_current_realm::<C>(context);
+    };
+
+    let previous = context.enter_realm(realm);
+    let result = register_in_current_realm::<C>(context);
+    context.enter_realm(previous);
+    result
+}
+
+fn register_in_current_realm<C: Class>(context: &mut Context) -> JsResult<StandardConstructor> {
+    if context.realm().has_class::<C>() {
+        return Err(JsNativeError::typ()
+            .with_message(format!(
+                "the platform interface `{}` is already registered",
+                C::NAME
+            ))
+            .into());
+    }
+
+    let mut class_builder = ClassBuilder::new::<C>(context);
+    C::init(&mut class_builder)?;
+    let class = class_builder.build();
+
+    let name = JsString::from(C::NAME);
+    let prototype = class.prototype();
+    if !prototype.has_own_property(JsSymbol::to_string_tag(), context)? {
+        prototype.define_property_or_throw(
+            JsSymbol::to_string_tag(),
+            PropertyDescriptor::builder()
+                .value(name.clone())
+                .writable(false)
+                .enumerable(false)
+                .configurable(true),
+            context,
+        )?;
+    }
+
+    context.register_global_property(
+        name,
+        class.constructor(),
+        Attribute::WRITABLE | Attribute::CONFIGURABLE,
+    )?;
+    context.realm().register_class::<C>(class.clone());
+
+    Ok(class)
+}
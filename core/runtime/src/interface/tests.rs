@@ -0,0 +1,76 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::class::{Class, ClassBuilder};
+use boa_engine::{
+    Context, Finalize, JsData, JsResult, JsValue, NativeFunction, Source, Trace, js_string,
+};
+use indoc::indoc;
+
+/// A minimal interface, as an out-of-tree crate would define it.
+#[derive(Debug, Trace, Finalize, JsData)]
+struct Counter;
+
+impl Class for Counter {
+    const NAME: &'static str = "Counter";
+
+    fn init(class: &mut ClassBuilder<'_>) -> JsResult<()> {
+        class.method(
+            js_string!("next"),
+            0,
+            NativeFunction::from_fn_ptr(|_, _, _| Ok(JsValue::new(1))),
+        );
+        Ok(())
+    }
+
+    fn data_constructor(_: &JsValue, _: &[JsValue], _: &mut Context) -> JsResult<Self> {
+        Ok(Self)
+    }
+}
+
+#[test]
+fn register_platform_interface() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            let class = super::register_platform_interface::<Counter>(None, context).unwrap();
+            let registered = context.realm().get_class::<Counter>().unwrap();
+            assert_eq!(registered.constructor(), class.constructor());
+            assert!(super::register_platform_interface::<Counter>(None, context).is_err());
+        }),
+        TestAction::run(indoc! {r#"
+            const counter = new Counter();
+            assertEq(counter.next(), 1);
+            assertEq(Object.prototype.toString.call(counter), "[object Counter]");
+
+            const descriptor = Object.getOwnPropertyDescriptor(globalThis, "Counter");
+            assert(descriptor.writable);
+            assert(!descriptor.enumerable);
+            assert(descriptor.configurable);
+        "#}),
+    ]);
+}
+
+#[test]
+fn register_platform_interface_in_realm() {
+    let context = &mut Context::default();
+    let realm = context.create_realm().unwrap();
+    super::register_platform_interface::<Counter>(Some(realm.clone()), context).unwrap();
+
+    assert!(realm.has_class::<Counter>());
+    assert!(!context.realm().has_class::<Counter>());
+    assert!(
+        !context
+            .global_object()
+            .has_own_property(js_string!("Counter"), context)
+            .unwrap()
+    );
+
+    context.enter_realm(realm);
+    let result = context.eval(Source::from_bytes("typeof Counter")).unwrap();
+    assert_eq!(
+        result
+            .as_string()
+            .map(|s| s.to_std_string_escaped())
+            .as_deref(),
+        Some("function")
+    );
+}
@@ -2,7 +2,7 @@
 //! timeouts.
 
 use boa_engine::interop::JsRest;
-use boa_engine::job::{NativeJob, TimeoutJob};
+use boa_engine::job::{NativeJob, TaskPriority, TimeoutJob};
 use boa_engine::object::builtins::JsFunction;
 use boa_engine::value::{IntegerOrInfinity, Nullable};
 use boa_engine::{
@@ -73,13 +73,17 @@ fn handle(
         return Ok(JsValue::undefined());
     }
 
-    // Call the handler function.
+    // Call the handler function, reporting the exception it throws to the global scope
+    // instead of propagating it out of the job queue.
     // The spec says we should still reschedule an interval even if the function
     // throws an error.
-    let result = function_ref.call(&JsValue::undefined(), &args, context);
+    let result = match function_ref.call(&JsValue::undefined(), &args, context) {
+        Ok(_) => Ok(JsValue::undefined()),
+        Err(err) => crate::report::report_exception(&err, context).map(|()| JsValue::undefined()),
+    };
     if let Some(delay) = reschedule {
         if handler_map.borrow().is_interval_valid(id) {
-            let job = TimeoutJob::new(
+            let job = timer_job(
                 NativeJob::new(move |context| {
                     handle(handler_map, id, function_ref, args, reschedule, context)
                 }),
@@ -94,6 +98,12 @@ fn handle(
     result
 }
 
+/// Creates the job running a timer after `delay` milliseconds. Timers are tasks of the
+/// timer task source, which run in the default, user-visible, priority lane.
+fn timer_job(job: NativeJob, delay: u64) -> TimeoutJob {
+    TimeoutJob::new(job, delay).with_priority(TaskPriority::UserVisible)
+}
+
 /// Set a timeout to call the given function after the given delay.
 /// The `code` version of this function is not supported at the moment.
 ///
@@ -126,7 +136,7 @@ pub fn set_timeout(
     // Get ownership of rest arguments.
     let rest = rest.to_vec();
 
-    let job = TimeoutJob::new(
+    let job = timer_job(
         NativeJob::new(move |context| handle(handler_map, id, function_ref, rest, None, context)),
         delay,
     );
@@ -166,7 +176,7 @@ pub fn set_interval(
     // Get ownership of rest arguments.
     let rest = rest.to_vec();
 
-    let job = TimeoutJob::new(
+    let job = timer_job(
         NativeJob::new(move |context| {
             handle(handler_map, id, function_ref, rest, Some(delay), context)
         }),
@@ -110,20 +110,26 @@ pub mod console;
 #[doc(inline)]
 pub use console::{Console, ConsoleState, DefaultLogger, Logger, NullLogger};
 
+pub mod base64;
 pub mod clone;
 #[cfg(feature = "fetch")]
 pub mod fetch;
+pub mod interface;
 pub mod interval;
+pub mod locale;
 pub mod microtask;
+pub mod report;
 pub mod store;
 pub mod text;
 #[cfg(feature = "url")]
 pub mod url;
+pub mod window;
 
 pub mod extensions;
 
 use crate::extensions::{
-    EncodingExtension, MicrotaskExtension, StructuredCloneExtension, TimeoutExtension,
+    Base64Extension, EncodingExtension, ErrorReportingExtension, MicrotaskExtension,
+    StructuredCloneExtension, TimeoutExtension,
 };
 pub use extensions::RuntimeExtension;
 
@@ -140,8 +146,10 @@ pub fn register(
     (
         TimeoutExtension,
         EncodingExtension,
+        Base64Extension,
         MicrotaskExtension,
         StructuredCloneExtension,
+        ErrorReportingExtension::default(),
         #[cfg(feature = "url")]
         extensions::UrlExtension,
         extensions,
@@ -0,0 +1,52 @@
+//! Boa's handling of the user's preferred locale.
+//!
+//! The locale itself is stored in the [`Context`] (see [`Context::set_locale`]), which feeds it
+//! to the default locale of `Intl`. This module adds the host-facing side: changing the locale
+//! at runtime through [`set_locale`] fires the [`languagechange`][mdn] event. Since the global
+//! object is not an `EventTarget` in this runtime, the event is delivered to the global
+//! `onlanguagechange` handler.
+//!
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/languagechange_event
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{Context, JsResult, JsString, js_string};
+
+/// Change the user's preferred locale, as a BCP 47 language tag, and fire the
+/// `languagechange` event on the global if the locale changed. Passing `None` reverts to
+/// the locale of the system.
+///
+/// Exceptions thrown by the `onlanguagechange` handler are reported to the global scope
+/// through [`report_exception`][crate::report::report_exception].
+///
+/// # Errors
+/// Returns a `RangeError` if the locale is not a structurally valid language tag. Also
+/// returns an error if the `onlanguagechange` property cannot be read, or if the exception
+/// thrown by the handler cannot be reported.
+pub fn set_locale(locale: Option<JsString>, context: &mut Context) -> JsResult<()> {
+    let previous = context.locale().cloned();
+    context.set_locale(locale)?;
+    if context.locale() == previous.as_ref() {
+        return Ok(());
+    }
+
+    let global = context.global_object();
+    let handler = global.get(js_string!("onlanguagechange"), context)?;
+    if let Some(handler) = handler.as_callable() {
+        let event = ObjectInitializer::new(context)
+            .property(
+                js_string!("type"),
+                js_string!("languagechange"),
+                Attribute::all(),
+            )
+            .build();
+        if let Err(err) = handler.call(&global.into(), &[event.into()], context) {
+            crate::report::report_exception(&err, context)?;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use crate::test::{TestAction, run_test_actions};
+use boa_engine::js_string;
+use indoc::indoc;
+
+#[test]
+fn set_locale() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            let events = [];
+            globalThis.onlanguagechange = (event) => events.push(event.type);
+        "#}),
+        TestAction::inspect_context(|context| {
+            super::set_locale(Some(js_string!("fr")), context).unwrap();
+            assert_eq!(context.locale(), Some(&js_string!("fr")));
+
+            // Setting the same locale again, in any case, doesn't fire the event.
+            super::set_locale(Some(js_string!("FR")), context).unwrap();
+            assert_eq!(context.locale(), Some(&js_string!("fr")));
+
+            // Invalid tags are rejected without changing the locale.
+            assert!(super::set_locale(Some(js_string!("fr_FR")), context).is_err());
+            assert_eq!(context.locale(), Some(&js_string!("fr")));
+
+            super::set_locale(None, context).unwrap();
+            assert_eq!(context.locale(), None);
+        }),
+        TestAction::run(r#"assertEq(events.join(), "languagechange,languagechange");"#),
+    ]);
+}
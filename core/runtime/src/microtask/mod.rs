@@ -16,10 +16,17 @@ pub mod js_module {
     /// microtask to be executed at a safe time prior to control returning to
     /// the browser's event loop.
     ///
+    /// Exceptions thrown by the callback are reported to the global scope through
+    /// [`report_exception`][crate::report::report_exception], so the remaining jobs still
+    /// run.
+    ///
     /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/queueMicrotask
     pub fn queue_microtask(callback: JsFunction, context: &mut Context) {
         context.enqueue_job(Job::from(PromiseJob::new(move |context| {
-            callback.call(&JsValue::undefined(), &[], context)
+            if let Err(err) = callback.call(&JsValue::undefined(), &[], context) {
+                crate::report::report_exception(&err, context)?;
+            }
+            Ok(JsValue::undefined())
         })));
     }
 }
@@ -0,0 +1,636 @@
+//! Boa's implementation of error reporting to the global scope.
+//!
+//! This module contains the [`reportError()`][mdn] function, the [`ErrorEvent`] and
+//! [`PromiseRejectionEvent`] classes, and the machinery firing the global `error`,
+//! `unhandledrejection` and `rejectionhandled` events. Since the global object is not an
+//! `EventTarget` in this runtime, events are delivered to the global `onerror`,
+//! `onunhandledrejection` and `onrejectionhandled` handlers. Events that aren't canceled
+//! by a handler are forwarded to the host through an [`ErrorReporter`].
+//!
+//! Unhandled rejections can only be observed if the engine reports them, so the context
+//! must be built with [`RejectionTrackerHooks`] (or host hooks forwarding to
+//! [`track_promise_rejection`]).
+//!
+//! More information:
+//!  - [WHATWG `reportError` specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#dom-reporterror
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/reportError
+#![allow(clippy::needless_pass_by_value)]
+
+#[cfg(test)]
+mod tests;
+
+use boa_engine::builtins::promise::{OperationType, PromiseState};
+use boa_engine::class::Class;
+use boa_engine::context::HostHooks;
+use boa_engine::job::{GenericJob, Job};
+use boa_engine::object::builtins::JsPromise;
+use boa_engine::realm::Realm;
+use boa_engine::value::{Convert, TryFromJs};
+use boa_engine::{
+    Context, Finalize, JsData, JsError, JsObject, JsResult, JsString, JsValue, Trace, boa_class,
+    boa_module, js_string,
+};
+use boa_gc::{Gc, GcRefCell};
+use std::io::Write;
+use std::mem;
+use std::rc::Rc;
+
+/// A trait that can be used to observe the errors left unhandled by scripts.
+pub trait ErrorReporter {
+    /// Called with an `error` event that the global `onerror` handler didn't cancel.
+    ///
+    /// # Errors
+    /// Returning an error will throw an exception in JavaScript.
+    fn report_error(&self, event: &ErrorEvent, context: &mut Context) -> JsResult<()>;
+
+    /// Called with an `unhandledrejection` event that the global `onunhandledrejection`
+    /// handler didn't cancel.
+    ///
+    /// # Errors
+    /// Returning an error will throw an exception in JavaScript.
+    fn report_unhandled_rejection(
+        &self,
+        event: &PromiseRejectionEvent,
+        context: &mut Context,
+    ) -> JsResult<()>;
+}
+
+/// The default implementation for reporting errors.
+///
+/// Implements the [`ErrorReporter`] trait and prints all unhandled errors to stderr.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultErrorReporter;
+
+impl ErrorReporter for DefaultErrorReporter {
+    fn report_error(&self, event: &ErrorEvent, _context: &mut Context) -> JsResult<()> {
+        writeln!(
+            std::io::stderr(),
+            "{}",
+            event.message.to_std_string_escaped()
+        )
+        .map_err(JsError::from_rust)
+    }
+
+    fn report_unhandled_rejection(
+        &self,
+        event: &PromiseRejectionEvent,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        writeln!(
+            std::io::stderr(),
+            "Uncaught (in promise) {}",
+            event.reason.display()
+        )
+        .map_err(JsError::from_rust)
+    }
+}
+
+/// The internal state of the error reporting module.
+#[derive(Trace, Finalize, JsData)]
+struct ErrorReportingState {
+    // SAFETY: Reporters are host objects that aren't reachable from JavaScript.
+    #[unsafe_ignore_trace]
+    reporter: Rc<dyn ErrorReporter>,
+    /// Promises rejected without a handler that haven't been notified yet.
+    pending_rejections: Vec<JsObject>,
+    /// Promises of the notification in progress that haven't been notified yet.
+    notifying_rejections: Vec<JsObject>,
+    /// The promise an `unhandledrejection` event is being fired for, cleared if the
+    /// promise gets a handler while the event is dispatched.
+    current_rejection: Option<JsObject>,
+    /// Promises notified as unhandled, which fire a `rejectionhandled` event once they get
+    /// a handler.
+    outstanding_rejections: Vec<JsObject>,
+    /// Whether a job notifying about `pending_rejections` is already enqueued.
+    notify_scheduled: bool,
+}
+
+impl ErrorReportingState {
+    fn new(reporter: Rc<dyn ErrorReporter>) -> Self {
+        Self {
+            reporter,
+            pending_rejections: Vec::new(),
+            notifying_rejections: Vec::new(),
+            current_rejection: None,
+            outstanding_rejections: Vec::new(),
+            notify_scheduled: false,
+        }
+    }
+
+    /// Get the error reporting state from the context, or add it to the context with a
+    /// [`DefaultErrorReporter`] if not present.
+    fn from_context(context: &mut Context) -> Gc<GcRefCell<Self>> {
+        if !context.has_data::<Gc<GcRefCell<Self>>>() {
+            context.insert_data(Gc::new(GcRefCell::new(Self::new(Rc::new(
+                DefaultErrorReporter,
+            )))));
+        }
+
+        context
+            .get_data::<Gc<GcRefCell<Self>>>()
+            .expect("Should have inserted.")
+            .clone()
+    }
+}
+
+/// The dictionary used to initialize an [`ErrorEvent`].
+#[derive(Debug, Clone, Default, TryFromJs, Trace, Finalize)]
+pub struct ErrorEventInit {
+    #[boa(default)]
+    message: Convert<JsString>,
+    #[boa(default)]
+    filename: Convert<JsString>,
+    #[boa(default)]
+    lineno: u32,
+    #[boa(default)]
+    colno: u32,
+    #[boa(default)]
+    error: JsValue,
+}
+
+/// The `ErrorEvent` class describes an error reported to the global scope.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/ErrorEvent
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct ErrorEvent {
+    event_type: JsString,
+    message: JsString,
+    filename: JsString,
+    lineno: u32,
+    colno: u32,
+    error: JsValue,
+    time_stamp: f64,
+    default_prevented: bool,
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl ErrorEvent {
+    /// Create a new `ErrorEvent` object. Meant to be called from the JavaScript constructor.
+    #[boa(constructor)]
+    pub fn new(
+        Convert(event_type): Convert<JsString>,
+        init: Option<ErrorEventInit>,
+        context: &mut Context,
+    ) -> Self {
+        let init = init.unwrap_or_default();
+        Self {
+            event_type,
+            message: init.message.0,
+            filename: init.filename.0,
+            lineno: init.lineno,
+            colno: init.colno,
+            error: init.error,
+            time_stamp: context.current_high_resolution_time(),
+            default_prevented: false,
+        }
+    }
+
+    /// The type of the event.
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    pub fn event_type(&self) -> JsString {
+        self.event_type.clone()
+    }
+
+    /// A human-readable description of the error.
+    #[boa(getter)]
+    pub fn message(&self) -> JsString {
+        self.message.clone()
+    }
+
+    /// The name of the script file in which the error occurred.
+    #[boa(getter)]
+    pub fn filename(&self) -> JsString {
+        self.filename.clone()
+    }
+
+    /// The line number of the script file on which the error occurred.
+    #[boa(getter)]
+    pub fn lineno(&self) -> u32 {
+        self.lineno
+    }
+
+    /// The column number of the script file on which the error occurred.
+    #[boa(getter)]
+    pub fn colno(&self) -> u32 {
+        self.colno
+    }
+
+    /// The value that was reported.
+    #[boa(getter)]
+    pub fn error(&self) -> JsValue {
+        self.error.clone()
+    }
+
+    /// The time at which the event was created, in milliseconds relative to the time
+    /// origin of the realm.
+    #[boa(getter)]
+    pub fn time_stamp(&self) -> f64 {
+        self.time_stamp
+    }
+
+    /// Whether a handler canceled the event.
+    #[boa(getter)]
+    pub fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+
+    /// Cancel the event.
+    pub fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+}
+
+/// The dictionary used to initialize a [`PromiseRejectionEvent`].
+#[derive(Debug, Clone, TryFromJs, Trace, Finalize)]
+pub struct PromiseRejectionEventInit {
+    #[boa(required)]
+    promise: JsObject,
+    #[boa(default)]
+    reason: JsValue,
+}
+
+/// The `PromiseRejectionEvent` class describes a promise rejection reported to the
+/// global scope.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/PromiseRejectionEvent
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct PromiseRejectionEvent {
+    event_type: JsString,
+    promise: JsObject,
+    reason: JsValue,
+    time_stamp: f64,
+    default_prevented: bool,
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl PromiseRejectionEvent {
+    /// Create a new `PromiseRejectionEvent` object. Meant to be called from the JavaScript
+    /// constructor.
+    #[boa(constructor)]
+    pub fn new(
+        Convert(event_type): Convert<JsString>,
+        init: PromiseRejectionEventInit,
+        context: &mut Context,
+    ) -> Self {
+        Self {
+            event_type,
+            promise: init.promise,
+            reason: init.reason,
+            time_stamp: context.current_high_resolution_time(),
+            default_prevented: false,
+        }
+    }
+
+    /// The type of the event.
+    #[boa(getter)]
+    #[boa(rename = "type")]
+    pub fn event_type(&self) -> JsString {
+        self.event_type.clone()
+    }
+
+    /// The promise that was rejected.
+    #[boa(getter)]
+    pub fn promise(&self) -> JsObject {
+        self.promise.clone()
+    }
+
+    /// The value the promise was rejected with.
+    #[boa(getter)]
+    pub fn reason(&self) -> JsValue {
+        self.reason.clone()
+    }
+
+    /// The time at which the event was created, in milliseconds relative to the time
+    /// origin of the realm.
+    #[boa(getter)]
+    pub fn time_stamp(&self) -> f64 {
+        self.time_stamp
+    }
+
+    /// Whether a handler canceled the event.
+    #[boa(getter)]
+    pub fn default_prevented(&self) -> bool {
+        self.default_prevented
+    }
+
+    /// Cancel the event.
+    pub fn prevent_default(&mut self) {
+        self.default_prevented = true;
+    }
+}
+
+/// Host hooks that track the promises rejected without a handler, firing an
+/// `unhandledrejection` event for the ones still unhandled once the current jobs ran, and
+/// a `rejectionhandled` event for the ones getting a handler afterwards.
+///
+/// Pass these to [`ContextBuilder::host_hooks`][hooks] when building the context.
+///
+/// [hooks]: boa_engine::context::ContextBuilder::host_hooks
+#[derive(Debug, Default, Clone, Copy)]
+pub struct RejectionTrackerHooks;
+
+impl HostHooks for RejectionTrackerHooks {
+    fn promise_rejection_tracker(
+        &self,
+        promise: &JsObject,
+        operation: OperationType,
+        context: &mut Context,
+    ) {
+        track_promise_rejection(promise, operation, context);
+    }
+}
+
+/// The rejection tracking behind [`RejectionTrackerHooks`]. Custom host hooks can forward
+/// their `promise_rejection_tracker` to this function to get `unhandledrejection` events.
+pub fn track_promise_rejection(
+    promise: &JsObject,
+    operation: OperationType,
+    context: &mut Context,
+) {
+    let state = ErrorReportingState::from_context(context);
+    let mut state = state.borrow_mut();
+
+    match operation {
+        OperationType::Reject => {
+            state.pending_rejections.push(promise.clone());
+            if !state.notify_scheduled {
+                state.notify_scheduled = true;
+                drop(state);
+
+                let realm = context.realm().clone();
+                context.enqueue_job(Job::GenericJob(GenericJob::new(
+                    |context| {
+                        notify_rejected_promises(context)?;
+                        Ok(JsValue::undefined())
+                    },
+                    realm,
+                )));
+            }
+        }
+        OperationType::Handle => {
+            // Promises that weren't notified yet are simply not notified anymore.
+            if remove_promise(&mut state.pending_rejections, promise)
+                || remove_promise(&mut state.notifying_rejections, promise)
+            {
+                return;
+            }
+            if state
+                .current_rejection
+                .as_ref()
+                .is_some_and(|current| JsObject::equals(current, promise))
+            {
+                state.current_rejection = None;
+                return;
+            }
+            if !remove_promise(&mut state.outstanding_rejections, promise) {
+                return;
+            }
+            drop(state);
+
+            let promise = promise.clone();
+            let realm = context.realm().clone();
+            context.enqueue_job(Job::GenericJob(GenericJob::new(
+                move |context| {
+                    notify_handled_rejection(promise, context)?;
+                    Ok(JsValue::undefined())
+                },
+                realm,
+            )));
+        }
+    }
+}
+
+/// Remove `promise` from `promises`, returning `true` if it was found.
+fn remove_promise(promises: &mut Vec<JsObject>, promise: &JsObject) -> bool {
+    let Some(index) = promises.iter().position(|p| JsObject::equals(p, promise)) else {
+        return false;
+    };
+    promises.remove(index);
+    true
+}
+
+/// Call the global event handler named `handler` with `event`, reporting the exception it
+/// throws.
+fn dispatch_global_event(
+    handler: JsString,
+    event: &JsObject,
+    context: &mut Context,
+) -> JsResult<()> {
+    let global = context.global_object();
+    let handler = global.get(handler, context)?;
+    if let Some(handler) = handler.as_callable() {
+        if let Err(err) = handler.call(&global.into(), &[event.clone().into()], context) {
+            report_exception(&err, context)?;
+        }
+    }
+    Ok(())
+}
+
+/// Fire a `rejectionhandled` event for `promise`, which got a handler after being notified
+/// as unhandled.
+fn notify_handled_rejection(promise: JsObject, context: &mut Context) -> JsResult<()> {
+    let reason = match JsPromise::from_object(promise.clone()).map(|p| p.state()) {
+        Ok(PromiseState::Rejected(reason)) => reason,
+        _ => JsValue::undefined(),
+    };
+    let event = PromiseRejectionEvent {
+        event_type: js_string!("rejectionhandled"),
+        promise,
+        reason,
+        time_stamp: context.current_high_resolution_time(),
+        default_prevented: false,
+    };
+    let event_obj = PromiseRejectionEvent::from_data(event, context)?;
+    dispatch_global_event(js_string!("onrejectionhandled"), &event_obj, context)
+}
+
+/// Fire an `unhandledrejection` event for every promise that was rejected without a
+/// handler since the last notification.
+///
+/// Promises that get a handler while the events are dispatched aren't notified, and
+/// the ones that get a handler later fire a `rejectionhandled` event.
+///
+/// This is called automatically by a job enqueued by [`track_promise_rejection`], but
+/// embedders running their own event loop can call it at the end of a microtask checkpoint.
+///
+/// # Errors
+/// Returns an error if an event object cannot be created.
+pub fn notify_rejected_promises(context: &mut Context) -> JsResult<()> {
+    let state = ErrorReportingState::from_context(context);
+    let reporter = {
+        let mut state = state.borrow_mut();
+        state.notify_scheduled = false;
+        let pending = mem::take(&mut state.pending_rejections);
+        state.notifying_rejections.extend(pending);
+        state.reporter.clone()
+    };
+
+    loop {
+        let promise = {
+            let mut state = state.borrow_mut();
+            if state.notifying_rejections.is_empty() {
+                break;
+            }
+            state.notifying_rejections.remove(0)
+        };
+        let Ok(js_promise) = JsPromise::from_object(promise.clone()) else {
+            continue;
+        };
+        let PromiseState::Rejected(reason) = js_promise.state() else {
+            continue;
+        };
+        state.borrow_mut().current_rejection = Some(promise.clone());
+
+        let event = PromiseRejectionEvent {
+            event_type: js_string!("unhandledrejection"),
+            promise: promise.clone(),
+            reason,
+            time_stamp: context.current_high_resolution_time(),
+            default_prevented: false,
+        };
+        let event_obj = PromiseRejectionEvent::from_data(event, context)?;
+        dispatch_global_event(js_string!("onunhandledrejection"), &event_obj, context)?;
+
+        // The promise is outstanding unless a handler was attached during the dispatch.
+        {
+            let mut state = state.borrow_mut();
+            if state.current_rejection.take().is_some() {
+                state.outstanding_rejections.push(promise);
+            }
+        }
+
+        let event = event_obj
+            .downcast_ref::<PromiseRejectionEvent>()
+            .expect("event object should be a PromiseRejectionEvent")
+            .clone();
+        if !event.default_prevented {
+            reporter.report_unhandled_rejection(&event, context)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// [Report an exception][spec] to the global scope.
+///
+/// The global `onerror` handler is called with the error, and the error is forwarded to
+/// the registered [`ErrorReporter`] unless the handler returns `true`. Embedders should
+/// call this for exceptions propagating out of scripts and jobs.
+///
+/// # Errors
+/// Returns an error if the `onerror` property cannot be read.
+///
+/// [spec]: https://html.spec.whatwg.org/multipage/webappapis.html#report-an-exception
+pub fn report_exception(error: &JsError, context: &mut Context) -> JsResult<()> {
+    let error = error.to_opaque(context);
+    report_error_value(error, context)
+}
+
+/// Create the `error` event describing `error`.
+fn error_event(error: JsValue, context: &mut Context) -> ErrorEvent {
+    let description = error.to_string(context).map_or_else(
+        |_| error.display().to_string(),
+        |s| s.to_std_string_escaped(),
+    );
+
+    ErrorEvent {
+        event_type: js_string!("error"),
+        message: JsString::from(format!("Uncaught {description}")),
+        filename: JsString::default(),
+        lineno: 0,
+        colno: 0,
+        error,
+        time_stamp: context.current_high_resolution_time(),
+        default_prevented: false,
+    }
+}
+
+fn report_error_value(error: JsValue, context: &mut Context) -> JsResult<()> {
+    let event = error_event(error, context);
+    let reporter = ErrorReportingState::from_context(context)
+        .borrow()
+        .reporter
+        .clone();
+
+    // The `onerror` handler of the global is special-cased: it receives the fields of the
+    // event as arguments, and cancels the event by returning `true`.
+    let global = context.global_object();
+    let handler = global.get(js_string!("onerror"), context)?;
+    if let Some(handler) = handler.as_callable() {
+        let args = [
+            event.message.clone().into(),
+            event.filename.clone().into(),
+            event.lineno.into(),
+            event.colno.into(),
+            event.error.clone(),
+        ];
+        match handler.call(&global.into(), &args, context) {
+            Ok(result) if result.as_boolean() == Some(true) => return Ok(()),
+            Ok(_) => {}
+            // Errors thrown by the handler go straight to the host instead of being
+            // reported to `onerror` again.
+            Err(err) => {
+                let error = err.to_opaque(context);
+                let handler_event = error_event(error, context);
+                reporter.report_error(&handler_event, context)?;
+            }
+        }
+    }
+
+    reporter.report_error(&event, context)
+}
+
+/// JavaScript module containing the `reportError` function and the event classes.
+#[boa_module]
+pub mod js_module {
+    use boa_engine::{Context, JsResult, JsValue};
+
+    type ErrorEvent = super::ErrorEvent;
+    type PromiseRejectionEvent = super::PromiseRejectionEvent;
+
+    /// The [`reportError()`][mdn] method reports an error to the global scope, as if it
+    /// were an uncaught exception.
+    ///
+    /// # Errors
+    /// Returns an error if the `onerror` handler cannot be read.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/reportError
+    pub fn report_error(error: JsValue, context: &mut Context) -> JsResult<()> {
+        super::report_error_value(error, context)
+    }
+}
+
+/// Register the `reportError` function and the event classes in the realm, forwarding
+/// unhandled errors to `reporter`. Pass `None` as the realm to register globally.
+///
+/// If the classes are already registered in the realm, only the reporter is replaced.
+///
+/// # Errors
+/// If any of the classes fail to register, an error is returned.
+pub fn register<R: ErrorReporter + 'static>(
+    reporter: R,
+    realm: Option<Realm>,
+    context: &mut Context,
+) -> JsResult<()> {
+    context.insert_data(Gc::new(GcRefCell::new(ErrorReportingState::new(Rc::new(
+        reporter,
+    )))));
+
+    let registered = realm.as_ref().map_or_else(
+        || context.has_global_class::<ErrorEvent>(),
+        |realm| realm.has_class::<ErrorEvent>(),
+    );
+    if registered {
+        return Ok(());
+    }
+    js_module::boa_register(realm, context)
+}
@@ -0,0 +1,226 @@
+use super::{ErrorEvent, ErrorReporter, PromiseRejectionEvent, RejectionTrackerHooks};
+use crate::extensions::{ConsoleExtension, ErrorReportingExtension};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::context::ContextBuilder;
+use boa_engine::context::time::FixedClock;
+use boa_engine::{Context, JsResult, JsValue, js_string};
+use indoc::indoc;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A reporter recording every error it receives.
+#[derive(Debug, Default, Clone)]
+struct RecordingReporter {
+    reports: Rc<RefCell<Vec<String>>>,
+}
+
+impl ErrorReporter for RecordingReporter {
+    fn report_error(&self, event: &ErrorEvent, _context: &mut Context) -> JsResult<()> {
+        self.reports
+            .borrow_mut()
+            .push(event.message.to_std_string_escaped());
+        Ok(())
+    }
+
+    fn report_unhandled_rejection(
+        &self,
+        event: &PromiseRejectionEvent,
+        _context: &mut Context,
+    ) -> JsResult<()> {
+        self.reports
+            .borrow_mut()
+            .push(format!("Uncaught (in promise) {}", event.reason.display()));
+        Ok(())
+    }
+}
+
+#[test]
+fn event_constructors() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::run(indoc! {r#"
+            const error = new Error("boom");
+            const event = new ErrorEvent("error", { message: "boom", lineno: 3, error });
+            assertEq(event.type, "error");
+            assertEq(event.message, "boom");
+            assertEq(event.filename, "");
+            assertEq(event.lineno, 3);
+            assertEq(event.colno, 0);
+            assertEq(event.error, error);
+            assert(event.timeStamp >= 0);
+            assert(!event.defaultPrevented);
+            event.preventDefault();
+            assert(event.defaultPrevented);
+
+            const promise = Promise.resolve();
+            const rejection = new PromiseRejectionEvent("unhandledrejection", { promise, reason: 1 });
+            assertEq(rejection.type, "unhandledrejection");
+            assertEq(rejection.promise, promise);
+            assertEq(rejection.reason, 1);
+        "#}),
+    ]);
+}
+
+#[test]
+fn report_error() {
+    let context = &mut Context::default();
+    let reporter = RecordingReporter::default();
+    crate::report::register(reporter.clone(), None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                let seen = [];
+                reportError(new TypeError("first"));
+                globalThis.onerror = (message, filename, lineno, colno, error) => {
+                    seen.push(message);
+                    return error === "second";
+                };
+                reportError("second");
+                reportError("third");
+                assertEq(seen.join(), "Uncaught second,Uncaught third");
+            "#}),
+        ],
+        context,
+    );
+
+    assert_eq!(
+        *reporter.reports.borrow(),
+        ["Uncaught TypeError: first", "Uncaught third"]
+    );
+}
+
+#[test]
+fn register_custom_reporter() {
+    let context = &mut Context::default();
+    let reporter = RecordingReporter::default();
+    crate::register(
+        (
+            ConsoleExtension::default(),
+            ErrorReportingExtension(reporter.clone()),
+        ),
+        None,
+        context,
+    )
+    .unwrap();
+
+    run_test_actions_with([TestAction::run(r#"reportError("custom");"#)], context);
+
+    assert_eq!(*reporter.reports.borrow(), ["Uncaught custom"]);
+}
+
+#[test]
+fn unhandled_rejection() {
+    let context = &mut ContextBuilder::new()
+        .host_hooks(Rc::new(RejectionTrackerHooks))
+        .build()
+        .unwrap();
+    let reporter = RecordingReporter::default();
+    crate::report::register(reporter.clone(), None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                let reasons = [];
+                globalThis.onunhandledrejection = (event) => {
+                    reasons.push(event.reason);
+                    if (event.reason === "canceled") {
+                        event.preventDefault();
+                    }
+                };
+                Promise.reject("unhandled");
+                Promise.reject("canceled");
+                Promise.reject("handled").catch(() => {});
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.run_jobs().unwrap();
+            }),
+            TestAction::run(r#"assertEq(reasons.join(), "unhandled,canceled");"#),
+        ],
+        context,
+    );
+
+    assert_eq!(
+        *reporter.reports.borrow(),
+        [format!(
+            "Uncaught (in promise) {}",
+            JsValue::from(js_string!("unhandled")).display()
+        )]
+    );
+}
+
+#[test]
+fn rejection_handled() {
+    let context = &mut ContextBuilder::new()
+        .host_hooks(Rc::new(RejectionTrackerHooks))
+        .build()
+        .unwrap();
+    crate::report::register(RecordingReporter::default(), None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                let events = [];
+                globalThis.onunhandledrejection = (event) => {
+                    events.push(`unhandled ${event.reason}`);
+                    if (event.reason === "early") {
+                        event.promise.catch(() => {});
+                    }
+                };
+                globalThis.onrejectionhandled = (event) => {
+                    events.push(`${event.type} ${event.reason}`);
+                };
+                const late = Promise.reject("late");
+                Promise.reject("early");
+            "#}),
+            TestAction::inspect_context(|context| {
+                context.run_jobs().unwrap();
+            }),
+            TestAction::run("late.catch(() => {});"),
+            TestAction::inspect_context(|context| {
+                context.run_jobs().unwrap();
+            }),
+            TestAction::run(indoc! {r#"
+                assertEq(events.join(), "unhandled late,unhandled early,rejectionhandled late");
+            "#}),
+        ],
+        context,
+    );
+}
+
+#[test]
+fn job_errors_are_reported() {
+    let clock = Rc::new(FixedClock::default());
+    let context = &mut ContextBuilder::new().clock(clock.clone()).build().unwrap();
+    crate::interval::register(context).unwrap();
+    crate::microtask::register(None, context).unwrap();
+    let reporter = RecordingReporter::default();
+    crate::report::register(reporter.clone(), None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                let ran = [];
+                setTimeout(() => { throw new Error("timeout"); });
+                setTimeout(() => ran.push("timeout"));
+                queueMicrotask(() => { throw new Error("microtask"); });
+                queueMicrotask(() => ran.push("microtask"));
+            "#}),
+            TestAction::inspect_context(move |context| {
+                clock.forward(1);
+                context.run_jobs().unwrap();
+            }),
+            TestAction::run(r#"assertEq(ran.join(), "microtask,timeout");"#),
+        ],
+        context,
+    );
+
+    assert_eq!(
+        *reporter.reports.borrow(),
+        ["Uncaught Error: microtask", "Uncaught Error: timeout"]
+    );
+}
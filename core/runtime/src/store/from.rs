@@ -7,8 +7,10 @@ use boa_engine::object::builtins::{
     JsArray, JsArrayBuffer, JsDataView, JsDate, JsMap, JsRegExp, JsSet, JsTypedArray,
 };
 use boa_engine::property::PropertyKey;
-use boa_engine::{Context, JsError, JsObject, JsResult, JsString, JsValue, JsVariant, js_error};
-use std::collections::{HashMap, HashSet};
+use boa_engine::{
+    Context, JsBigInt, JsError, JsObject, JsResult, JsString, JsValue, JsVariant, js_error,
+};
+use std::collections::HashMap;
 
 /// A Map of seen objects when walking through the value. We use the address
 /// of the inner object as it is unique per JavaScript value.
@@ -30,49 +32,88 @@ impl SeenMap {
 /// The core logic of the [`JsValueStore::try_from_js`] function.
 fn try_from_js_object(
     value: &JsObject,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
-    // Have we seen this object? If so, return its clone.
+    // Have we seen this object? If so, return its clone. Transferred objects are
+    // always found here, as their stores are added to the map before walking.
     if let Some(o2) = seen.get(value) {
         return Ok(o2.clone());
     }
 
-    // Is it a transferable object?
-    let new_value = if transfer.contains(value) {
-        try_from_js_object_transfer(value, context)?
-    } else {
-        try_from_js_object_clone(value, transfer, seen, context)?
-    };
-
-    Ok(new_value)
+    try_from_js_object_clone(value, seen, context)
 }
 
-/// Transfer an object into a store instead of cloning it. See [mdn].
+/// Transfer objects into stores instead of cloning them. See [mdn].
 ///
-/// Only [transferable objects][to] can be transferred. Anything else will return an
-/// error. Since any object t
+/// Only [transferable objects][to] can be transferred. The transfer list is validated
+/// before anything is serialized, and the objects are only detached once the whole
+/// value was serialized, so a failed clone leaves them untouched.
 ///
 /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Transferable_objects
 /// [to]: https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Transferable_objects#supported_objects
-fn try_from_js_object_transfer(
-    object: &JsObject,
-    _context: &mut Context,
-) -> JsResult<JsValueStore> {
-    if let Some(mut buffer) = object.downcast_mut::<ArrayBuffer>() {
-        let data = buffer.detach(&JsValue::undefined())?;
-        let data = data.ok_or_else(unsupported_type)?;
+pub(super) struct Transfer(Vec<(JsObject, JsValueStore)>);
+
+impl Transfer {
+    /// Validate the transfer list, adding a store for each transferred object to `seen`.
+    ///
+    /// # Errors
+    /// Returns a `DataCloneError` if an object is listed twice, is not transferable, or
+    /// was already detached.
+    pub(super) fn new(transfer: Vec<JsObject>, seen: &mut SeenMap) -> JsResult<Self> {
+        let mut objects: Vec<(JsObject, JsValueStore)> = Vec::with_capacity(transfer.len());
+        for object in transfer {
+            if objects.iter().any(|(o, _)| o == &object) {
+                return Err(js_error!(
+                    Error: "DataCloneError: an object is listed more than once in the transfer list"
+                ));
+            }
+
+            match object.downcast_ref::<ArrayBuffer>() {
+                Some(buffer) if buffer.data().is_none() => {
+                    return Err(js_error!(
+                        Error: "DataCloneError: a detached ArrayBuffer cannot be transferred"
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    return Err(js_error!(
+                        Error: "DataCloneError: the transfer list contains a non-transferable object"
+                    ));
+                }
+            }
+
+            let store = JsValueStore::empty();
+            seen.insert(&object, store.clone());
+            objects.push((object, store));
+        }
 
-        Ok(JsValueStore::new(ValueStoreInner::ArrayBuffer(data)))
-    } else {
-        Err(unsupported_type())
+        Ok(Self(objects))
+    }
+
+    /// Detach the transferred objects, moving their data into their stores.
+    pub(super) fn detach(self) -> JsResult<()> {
+        for (object, mut store) in self.0 {
+            let inner = if let Some(mut buffer) = object.downcast_mut::<ArrayBuffer>() {
+                let data = buffer.detach(&JsValue::undefined())?;
+                ValueStoreInner::ArrayBuffer(data.ok_or_else(unsupported_type)?)
+            } else {
+                return Err(unsupported_type());
+            };
+
+            // SAFETY: The store was created empty by `Transfer::new`, and is only
+            // filled here.
+            unsafe {
+                store.replace(inner);
+            }
+        }
+
+        Ok(())
     }
 }
 
 fn try_from_array_clone(
     array: &JsArray,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
@@ -92,7 +133,7 @@ fn try_from_array_clone(
             .get(&i.into())
             .and_then(|x| x.value().cloned());
         if let Some(v) = v {
-            let v = try_from_js_value(&v, transfer, seen, context)?;
+            let v = try_from_js_value(&v, seen, context)?;
             inner.push(Some(v));
         } else {
             inner.push(None);
@@ -122,13 +163,12 @@ fn try_from_array_buffer_clone(
 fn clone_typed_array(
     original: &JsObject,
     buffer: &JsTypedArray,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
     let kind = buffer.kind().ok_or_else(unsupported_type)?;
     let buffer = buffer.buffer(context)?;
-    let buffer = try_from_js_value(&buffer, transfer, seen, context)?;
+    let buffer = try_from_js_value(&buffer, seen, context)?;
     let dolly = JsValueStore::new(ValueStoreInner::TypedArray { kind, buffer });
     seen.insert(original, dolly.clone());
     Ok(dolly)
@@ -150,6 +190,12 @@ fn clone_date(
     Ok(stored)
 }
 
+fn clone_bigint_object(original: &JsObject, bigint: &JsBigInt, seen: &mut SeenMap) -> JsValueStore {
+    let stored = JsValueStore::new(ValueStoreInner::BigIntObject(bigint.as_inner().clone()));
+    seen.insert(original, stored.clone());
+    stored
+}
+
 fn clone_regexp(
     original: &JsObject,
     regexp: &JsRegExp,
@@ -167,7 +213,6 @@ fn clone_regexp(
 fn try_from_map(
     original: &JsObject,
     map: &JsMap,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
@@ -176,8 +221,8 @@ fn try_from_map(
     seen.insert(original, store.clone());
 
     map.for_each_native(|k, v| {
-        let key = try_from_js_value(&k, transfer, seen, context)?;
-        let value = try_from_js_value(&v, transfer, seen, context)?;
+        let key = try_from_js_value(&k, seen, context)?;
+        let value = try_from_js_value(&v, seen, context)?;
         new_map.push((key, value));
 
         Ok(())
@@ -194,7 +239,6 @@ fn try_from_map(
 fn try_from_set(
     original: &JsObject,
     set: &JsSet,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
@@ -203,7 +247,7 @@ fn try_from_set(
     seen.insert(original, store.clone());
 
     set.for_each_native(|v| {
-        let value = try_from_js_value(&v, transfer, seen, context)?;
+        let value = try_from_js_value(&v, seen, context)?;
         new_set.push(value);
 
         Ok(())
@@ -219,7 +263,6 @@ fn try_from_set(
 
 fn try_from_js_object_clone(
     object: &JsObject,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
@@ -228,17 +271,19 @@ fn try_from_js_object_clone(
     // https://developer.mozilla.org/en-US/docs/Web/API/Web_Workers_API/Structured_clone_algorithm#supported_types
 
     if let Ok(array) = JsArray::from_object(object.clone()) {
-        return try_from_array_clone(&array, transfer, seen, context);
+        return try_from_array_clone(&array, seen, context);
     } else if let Ok(map) = JsMap::from_object(object.clone()) {
-        return try_from_map(object, &map, transfer, seen, context);
+        return try_from_map(object, &map, seen, context);
     } else if let Ok(set) = JsSet::from_object(object.clone()) {
-        return try_from_set(object, &set, transfer, seen, context);
+        return try_from_set(object, &set, seen, context);
     } else if let Ok(ref buffer) = JsArrayBuffer::from_object(object.clone()) {
         return try_from_array_buffer_clone(object, buffer, seen);
     } else if let Ok(ref typed_array) = JsTypedArray::from_object(object.clone()) {
-        return clone_typed_array(object, typed_array, transfer, seen, context);
+        return clone_typed_array(object, typed_array, seen, context);
     } else if let Ok(ref date) = JsDate::from_object(object.clone()) {
         return clone_date(object, date, seen, context);
+    } else if let Some(bigint) = object.downcast_ref::<JsBigInt>() {
+        return Ok(clone_bigint_object(object, &bigint, seen));
     } else if let Ok(_error) = object.clone().downcast::<Error>() {
         return Err(js_error!(TypeError: "Errors are not supported yet."));
     } else if let Ok(ref regexp) = JsRegExp::from_object(object.clone()) {
@@ -248,6 +293,10 @@ fn try_from_js_object_clone(
     } else if object.is_callable() {
         // Functions are invalid.
         return Err(unsupported_type());
+    } else if is_temporal_object(object) {
+        // Temporal objects are not serializable yet. Refuse them instead of silently
+        // cloning them into empty objects, as they don't have any own properties.
+        return Err(js_error!(Error: "DataCloneError: Temporal objects cannot be cloned"));
     }
 
     // Create a new object and add own properties to it. This does not preserve
@@ -265,7 +314,7 @@ fn try_from_js_object_clone(
             PropertyKey::Index(i) => JsString::from(format!("{}", i.get())).into(),
         };
 
-        let v = try_from_js_value(&value, transfer, seen, context)?;
+        let v = try_from_js_value(&value, seen, context)?;
         fields.push((key, v));
     }
 
@@ -276,9 +325,31 @@ fn try_from_js_object_clone(
     Ok(dolly)
 }
 
+/// Returns `true` if the object is an instance of one of the `Temporal` classes.
+#[cfg(feature = "temporal")]
+fn is_temporal_object(object: &JsObject) -> bool {
+    use boa_engine::builtins::temporal::{
+        Duration, Instant, PlainDate, PlainDateTime, PlainMonthDay, PlainTime, PlainYearMonth,
+        ZonedDateTime,
+    };
+
+    object.is::<Duration>()
+        || object.is::<Instant>()
+        || object.is::<PlainDate>()
+        || object.is::<PlainDateTime>()
+        || object.is::<PlainMonthDay>()
+        || object.is::<PlainTime>()
+        || object.is::<PlainYearMonth>()
+        || object.is::<ZonedDateTime>()
+}
+
+#[cfg(not(feature = "temporal"))]
+fn is_temporal_object(_object: &JsObject) -> bool {
+    false
+}
+
 pub(super) fn try_from_js_value(
     value: &JsValue,
-    transfer: &HashSet<JsObject>,
     seen: &mut SeenMap,
     context: &mut Context,
 ) -> JsResult<JsValueStore> {
@@ -292,7 +363,7 @@ pub(super) fn try_from_js_value(
         JsVariant::BigInt(b) => Ok(JsValueStore::new(ValueStoreInner::BigInt(
             b.as_inner().clone(),
         ))),
-        JsVariant::Object(ref o) => try_from_js_object(o, transfer, seen, context),
+        JsVariant::Object(ref o) => try_from_js_object(o, seen, context),
 
         // Symbols cannot be transferred/cloned.
         JsVariant::Symbol(_) => Err(unsupported_type()),
@@ -4,7 +4,6 @@ use boa_engine::builtins::error::ErrorKind;
 use boa_engine::builtins::typed_array::TypedArrayKind;
 use boa_engine::value::TryIntoJs;
 use boa_engine::{Context, JsError, JsObject, JsResult, JsString, JsValue, js_error};
-use std::collections::HashSet;
 use std::sync::Arc;
 
 mod from;
@@ -70,6 +69,9 @@ enum ValueStoreInner {
     /// of it is, though.
     BigInt(RawBigInt),
 
+    /// A `BigInt` wrapper object, e.g. `Object(1n)`.
+    BigIntObject(RawBigInt),
+
     /// A dictionary of strings to values which should be reconstructed into
     /// a `JsObject`. Note: the prototype and constructor are not maintained,
     /// and during reconstruction the default `Object` prototype will be used.
@@ -196,8 +198,9 @@ impl JsValueStore {
         transfer: Vec<JsObject>,
     ) -> JsResult<Self> {
         let mut seen = from::SeenMap::default();
-        let transfer = transfer.into_iter().collect::<HashSet<_>>();
-        let v = from::try_from_js_value(value, &transfer, &mut seen, context)?;
+        let transfer = from::Transfer::new(transfer, &mut seen)?;
+        let v = from::try_from_js_value(value, &mut seen, context)?;
+        transfer.detach()?;
         Ok(v)
     }
 }
@@ -1,5 +1,6 @@
 //! All methods for deserializing a [`JsValueStore`] into a [`JsValue`].
 use crate::store::{JsValueStore, StringStore, ValueStoreInner, unsupported_type};
+use boa_engine::bigint::RawBigInt;
 use boa_engine::builtins::typed_array::TypedArrayKind;
 use boa_engine::object::builtins::{
     JsArray, JsArrayBuffer, JsDataView, JsDate, JsMap, JsRegExp, JsSet, js_typed_array_from_kind,
@@ -136,6 +137,18 @@ fn try_into_js_date(
     Ok(JsValue::from(date))
 }
 
+fn try_into_js_bigint_object(
+    store: &JsValueStore,
+    bigint: &RawBigInt,
+    seen: &mut ReverseSeenMap,
+    context: &mut Context,
+) -> JsResult<JsValue> {
+    let object = JsValue::from(JsBigInt::new(bigint.clone())).to_object(context)?;
+    seen.insert(store, object.clone());
+
+    Ok(JsValue::from(object))
+}
+
 fn try_into_regexp(
     store: &JsValueStore,
     source: &str,
@@ -188,6 +201,7 @@ pub(super) fn try_value_into_js(
         ValueStoreInner::Float(f) => Ok(JsValue::from(*f)),
         ValueStoreInner::String(s) => Ok(JsValue::from(s.to_js_string())),
         ValueStoreInner::BigInt(b) => Ok(JsValue::from(JsBigInt::new(b.clone()))),
+        ValueStoreInner::BigIntObject(b) => try_into_js_bigint_object(store, b, seen, context),
         ValueStoreInner::Object(fields) => try_fields_into_js_object(store, fields, seen, context),
         ValueStoreInner::Map(key_values) => try_into_js_map(store, key_values, seen, context),
         ValueStoreInner::Set(values) => try_into_js_set(store, values, seen, context),
@@ -0,0 +1,424 @@
+//! The [`matchMedia()`][mdn] machinery: a parser and evaluator for a practical subset of
+//! [Media Queries][spec], and the `MediaQueryList` class.
+//!
+//! Supported media types are `all`, `screen` and `print`. Supported media features are
+//! `width`, `height` (with their `min-` and `max-` prefixes), `orientation`,
+//! `prefers-color-scheme` and `prefers-reduced-motion`. Unknown media types and features
+//! never match, and a query that cannot be parsed is treated as `not all`.
+//!
+//! [spec]: https://drafts.csswg.org/mediaqueries/
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/matchMedia
+
+use super::{ColorScheme, ViewportState, WindowState};
+use boa_engine::interop::JsClass;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::object::builtins::JsFunction;
+use boa_engine::property::Attribute;
+use boa_engine::value::Convert;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, Trace, boa_class, js_error,
+    js_string,
+};
+
+/// How a range media feature compares to the viewport.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Comparison {
+    Min,
+    Max,
+    Exact,
+}
+
+impl Comparison {
+    fn matches(self, actual: f64, expected: f64) -> bool {
+        match self {
+            Self::Min => actual >= expected,
+            Self::Max => actual <= expected,
+            Self::Exact => (actual - expected).abs() < f64::EPSILON,
+        }
+    }
+}
+
+/// A media feature, e.g. `(min-width: 600px)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum MediaFeature {
+    Width(Comparison, f64),
+    Height(Comparison, f64),
+    /// `true` for `portrait`.
+    Portrait(Option<bool>),
+    ColorScheme(Option<ColorScheme>),
+    /// `true` for `reduce`.
+    ReducedMotion(bool),
+    /// A media feature this implementation doesn't support.
+    Unknown,
+}
+
+impl MediaFeature {
+    /// Parse the inside of the parentheses of a media feature. Returns `None` on a syntax
+    /// error.
+    fn parse(feature: &str) -> Option<Self> {
+        let (name, value) = match feature.split_once(':') {
+            Some((name, value)) => (name.trim(), Some(value.trim())),
+            None => (feature.trim(), None),
+        };
+
+        let (comparison, name) = if let Some(name) = name.strip_prefix("min-") {
+            (Comparison::Min, name)
+        } else if let Some(name) = name.strip_prefix("max-") {
+            (Comparison::Max, name)
+        } else {
+            (Comparison::Exact, name)
+        };
+
+        let feature = match (name, value) {
+            ("width", Some(value)) => Self::Width(comparison, parse_length(value)?),
+            ("height", Some(value)) => Self::Height(comparison, parse_length(value)?),
+            ("orientation", None) if comparison == Comparison::Exact => Self::Portrait(None),
+            ("orientation", Some("portrait")) => Self::Portrait(Some(true)),
+            ("orientation", Some("landscape")) => Self::Portrait(Some(false)),
+            ("prefers-color-scheme", None) => Self::ColorScheme(None),
+            ("prefers-color-scheme", Some("light")) => Self::ColorScheme(Some(ColorScheme::Light)),
+            ("prefers-color-scheme", Some("dark")) => Self::ColorScheme(Some(ColorScheme::Dark)),
+            ("prefers-reduced-motion", None | Some("reduce")) => Self::ReducedMotion(true),
+            ("prefers-reduced-motion", Some("no-preference")) => Self::ReducedMotion(false),
+            ("width" | "height" | "orientation", _) => return None,
+            (_, Some("")) => return None,
+            _ => Self::Unknown,
+        };
+
+        // Only range features accept the `min-` and `max-` prefixes.
+        match feature {
+            Self::Width(..) | Self::Height(..) | Self::Unknown => Some(feature),
+            _ if comparison == Comparison::Exact => Some(feature),
+            _ => Some(Self::Unknown),
+        }
+    }
+
+    fn matches(self, viewport: &ViewportState) -> bool {
+        match self {
+            Self::Width(comparison, width) => comparison.matches(viewport.width, width),
+            Self::Height(comparison, height) => comparison.matches(viewport.height, height),
+            Self::Portrait(portrait) => {
+                portrait.is_none_or(|portrait| (viewport.height >= viewport.width) == portrait)
+            }
+            Self::ColorScheme(scheme) => {
+                scheme.is_none_or(|scheme| viewport.color_scheme == scheme)
+            }
+            Self::ReducedMotion(reduce) => viewport.reduced_motion == reduce,
+            Self::Unknown => false,
+        }
+    }
+}
+
+/// Parse a length in CSS pixels. Font-relative lengths use the default font size of 16px.
+fn parse_length(value: &str) -> Option<f64> {
+    let (number, scale) = if let Some(number) = value.strip_suffix("px") {
+        (number, 1.0)
+    } else if let Some(number) = value
+        .strip_suffix("rem")
+        .or_else(|| value.strip_suffix("em"))
+    {
+        (number, 16.0)
+    } else if value == "0" {
+        (value, 1.0)
+    } else {
+        return None;
+    };
+
+    let number = number.parse::<f64>().ok()?;
+    number.is_finite().then_some(number * scale)
+}
+
+/// A single media query, e.g. `screen and (min-width: 600px)`.
+#[derive(Debug, Clone, PartialEq)]
+struct MediaQuery {
+    negated: bool,
+    /// Whether the media type of the query matches this runtime, which behaves as a screen.
+    media_type: bool,
+    features: Vec<MediaFeature>,
+}
+
+/// A token of a media query.
+#[derive(Debug, PartialEq)]
+enum Token<'a> {
+    Word(&'a str),
+    Parenthesized(&'a str),
+}
+
+/// Split a media query into words and parenthesized blocks. Returns `None` on unbalanced
+/// or nested parentheses.
+fn tokenize(query: &str) -> Option<Vec<Token<'_>>> {
+    let mut tokens = Vec::new();
+    let mut rest = query.trim_start();
+    while !rest.is_empty() {
+        if let Some(block) = rest.strip_prefix('(') {
+            let end = block.find(')')?;
+            if block[..end].contains('(') {
+                return None;
+            }
+            tokens.push(Token::Parenthesized(&block[..end]));
+            rest = &block[end + 1..];
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                .unwrap_or(rest.len());
+            if end == 0 {
+                return None;
+            }
+            tokens.push(Token::Word(&rest[..end]));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    Some(tokens)
+}
+
+impl MediaQuery {
+    /// Parse a single media query, which must already be in ASCII lowercase. Returns
+    /// `None` on a syntax error.
+    fn parse(query: &str) -> Option<Self> {
+        let mut tokens = tokenize(query)?.into_iter().peekable();
+
+        let negated = match tokens.peek() {
+            Some(Token::Word("not")) => {
+                tokens.next();
+                true
+            }
+            Some(Token::Word("only")) => {
+                tokens.next();
+                false
+            }
+            _ => false,
+        };
+
+        let mut expect_and = false;
+        let mut media_type = true;
+        if let Some(Token::Word(word)) = tokens.peek() {
+            media_type = match *word {
+                "all" | "screen" => true,
+                "and" | "not" | "only" | "or" => return None,
+                _ => false,
+            };
+            tokens.next();
+            expect_and = true;
+        }
+
+        let mut features = Vec::new();
+        loop {
+            if expect_and {
+                match tokens.next() {
+                    None => break,
+                    Some(Token::Word("and")) => {}
+                    Some(_) => return None,
+                }
+            }
+            let Some(Token::Parenthesized(feature)) = tokens.next() else {
+                return None;
+            };
+            features.push(MediaFeature::parse(feature)?);
+            expect_and = true;
+        }
+
+        Some(Self {
+            negated,
+            media_type,
+            features,
+        })
+    }
+
+    fn matches(&self, viewport: &ViewportState) -> bool {
+        let matches = self.media_type && self.features.iter().all(|f| f.matches(viewport));
+        matches != self.negated
+    }
+}
+
+/// A parsed media query list, e.g. `print, (max-width: 600px)`. Queries that couldn't be
+/// parsed are stored as `None`, and never match.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct MediaQueries(Vec<Option<MediaQuery>>);
+
+impl MediaQueries {
+    /// Parse a media query list.
+    pub(crate) fn parse(media: &str) -> Self {
+        let media = media.to_ascii_lowercase();
+        if media.trim().is_empty() {
+            return Self(Vec::new());
+        }
+        Self(media.split(',').map(MediaQuery::parse).collect())
+    }
+
+    /// Returns `true` if any of the queries matches `viewport`. An empty list always matches.
+    pub(crate) fn matches(&self, viewport: &ViewportState) -> bool {
+        self.0.is_empty() || self.0.iter().flatten().any(|query| query.matches(viewport))
+    }
+}
+
+/// The `MediaQueryList` class, which tracks whether a media query list matches the
+/// viewport and notifies listeners when that changes.
+///
+/// See the [MDN documentation][mdn] for more information.
+///
+/// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/MediaQueryList
+#[derive(Debug, Clone, Trace, Finalize, JsData)]
+pub struct MediaQueryList {
+    media: JsString,
+    #[unsafe_ignore_trace]
+    queries: MediaQueries,
+    /// Whether the queries matched the last time change events were evaluated.
+    pub(crate) last_matches: bool,
+    onchange: Option<JsObject>,
+    listeners: Vec<JsObject>,
+}
+
+impl MediaQueryList {
+    /// Create a list for `media`, evaluated against `viewport`.
+    pub(crate) fn new(media: JsString, viewport: &ViewportState) -> Self {
+        let queries = MediaQueries::parse(&media.to_std_string_escaped());
+        Self {
+            last_matches: queries.matches(viewport),
+            media,
+            queries,
+            onchange: None,
+            listeners: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the queries match `viewport`.
+    pub(crate) fn evaluate(&self, viewport: &ViewportState) -> bool {
+        self.queries.matches(viewport)
+    }
+
+    /// Returns `true` if the list has handlers for the `change` event.
+    pub(crate) fn has_change_handlers(&self) -> bool {
+        self.onchange.is_some() || !self.listeners.is_empty()
+    }
+
+    /// Returns the handlers to call for a `change` event, in order.
+    pub(crate) fn change_handlers(&self) -> Vec<JsObject> {
+        self.onchange
+            .iter()
+            .chain(&self.listeners)
+            .cloned()
+            .collect()
+    }
+
+    /// Create the `change` event for this list.
+    pub(crate) fn change_event(&self, matches: bool, context: &mut Context) -> JsObject {
+        ObjectInitializer::new(context)
+            .property(js_string!("type"), js_string!("change"), Attribute::all())
+            .property(js_string!("media"), self.media.clone(), Attribute::all())
+            .property(js_string!("matches"), matches, Attribute::all())
+            .build()
+    }
+
+    fn add_listener_object(&mut self, callback: Option<JsFunction>) {
+        let Some(callback) = callback else {
+            return;
+        };
+        let callback = JsObject::from(callback);
+        if !self
+            .listeners
+            .iter()
+            .any(|listener| JsObject::equals(listener, &callback))
+        {
+            self.listeners.push(callback);
+        }
+    }
+
+    fn remove_listener_object(&mut self, callback: Option<JsFunction>) {
+        let Some(callback) = callback else {
+            return;
+        };
+        let callback = JsObject::from(callback);
+        self.listeners
+            .retain(|listener| !JsObject::equals(listener, &callback));
+    }
+
+    /// Notify `list` of the viewport changes if it has `change` handlers. Lists without
+    /// handlers aren't kept alive by the window.
+    fn track(list: &JsClass<Self>, context: &mut Context) {
+        if list.borrow().has_change_handlers() {
+            WindowState::from_context(context)
+                .borrow_mut()
+                .track_media_query_list(list);
+        }
+    }
+}
+
+#[boa_class]
+#[boa(rename_all = "camelCase")]
+impl MediaQueryList {
+    /// `MediaQueryList` objects are created by `matchMedia()`.
+    #[boa(constructor)]
+    fn constructor() -> JsResult<Self> {
+        Err(js_error!(TypeError: "Illegal constructor"))
+    }
+
+    /// The media query list, as passed to `matchMedia()`.
+    #[boa(getter)]
+    fn media(&self) -> JsString {
+        self.media.clone()
+    }
+
+    /// Whether the media query list matches the current viewport.
+    #[boa(getter)]
+    fn matches(&self, context: &mut Context) -> bool {
+        let viewport = WindowState::from_context(context).borrow().viewport;
+        self.evaluate(&viewport)
+    }
+
+    #[boa(getter)]
+    fn onchange(&self) -> JsValue {
+        self.onchange
+            .clone()
+            .map_or_else(JsValue::null, JsValue::from)
+    }
+
+    #[allow(clippy::needless_pass_by_value)]
+    #[boa(setter)]
+    #[boa(rename = "onchange")]
+    fn set_onchange(this: JsClass<Self>, handler: JsValue, context: &mut Context) {
+        this.borrow_mut().onchange = handler.as_callable();
+        Self::track(&this, context);
+    }
+
+    /// Add a listener for the `change` event.
+    #[allow(clippy::needless_pass_by_value)]
+    #[boa(method)]
+    #[boa(length = 2)]
+    fn add_event_listener(
+        this: JsClass<Self>,
+        Convert(event_type): Convert<JsString>,
+        callback: Option<JsFunction>,
+        context: &mut Context,
+    ) {
+        if event_type == js_string!("change") {
+            this.borrow_mut().add_listener_object(callback);
+            Self::track(&this, context);
+        }
+    }
+
+    /// Remove a listener added with `addEventListener()`.
+    fn remove_event_listener(
+        &mut self,
+        Convert(event_type): Convert<JsString>,
+        callback: Option<JsFunction>,
+    ) {
+        if event_type == js_string!("change") {
+            self.remove_listener_object(callback);
+        }
+    }
+
+    /// The legacy alias of `addEventListener("change", callback)`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[boa(method)]
+    #[boa(length = 1)]
+    fn add_listener(this: JsClass<Self>, callback: Option<JsFunction>, context: &mut Context) {
+        this.borrow_mut().add_listener_object(callback);
+        Self::track(&this, context);
+    }
+
+    /// The legacy alias of `removeEventListener("change", callback)`.
+    fn remove_listener(&mut self, callback: Option<JsFunction>) {
+        self.remove_listener_object(callback);
+    }
+}
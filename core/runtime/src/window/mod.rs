@@ -0,0 +1,413 @@
+//! Boa's implementation of the host-facing parts of the [`Window`][mdn] interface.
+//!
+//! This runtime has no browsing contexts, so opening and closing windows is delegated to
+//! a [`WindowHost`] provided by the embedder. Since the global object is the window in
+//! this runtime, the `open()` and `close()` functions and the `closed` property are
+//! registered on the global object.
+//!
+//! The viewport the window is displayed in is described by a [`ViewportState`], which the
+//! embedder updates through [`set_viewport`]. It is exposed through the global
+//! `innerWidth`, `innerHeight`, `devicePixelRatio`, `scrollX` and `scrollY` properties,
+//! and changes to it are delivered to the global `onresize` and `onscroll` handlers.
+//! Media queries created by `matchMedia()` are evaluated against it, and fire their
+//! `change` events when it changes.
+//!
+//! More information:
+//!  - [WHATWG `window.open()` specification][spec]
+//!
+//! [spec]: https://html.spec.whatwg.org/multipage/nav-history-apis.html#dom-open
+//! [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window
+
+#[cfg(test)]
+mod tests;
+
+mod media;
+
+#[doc(inline)]
+pub use media::MediaQueryList;
+
+use boa_engine::interop::JsClass;
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::{Attribute, PropertyDescriptor};
+use boa_engine::realm::Realm;
+use boa_engine::{
+    Context, Finalize, JsData, JsObject, JsResult, JsString, JsValue, NativeFunction, Trace,
+    boa_module, js_string,
+};
+use boa_gc::{Gc, GcRefCell};
+use std::rc::Rc;
+
+/// A trait that can be implemented by embedders to support multiple windows.
+pub trait WindowHost {
+    /// Called by `open(url, target, features)` to open a new window. Missing arguments
+    /// are passed as empty strings, except `target` which defaults to `"_blank"`.
+    ///
+    /// Returns the object representing the new window, or `None` if no window was opened
+    /// (e.g. if a popup was blocked), in which case `open()` returns `null`.
+    ///
+    /// # Errors
+    /// Returning an error will throw an exception in JavaScript.
+    fn open(
+        &self,
+        url: &JsString,
+        target: &JsString,
+        features: &JsString,
+        context: &mut Context,
+    ) -> JsResult<Option<JsObject>>;
+
+    /// Called by `close()` the first time the script closes its own window.
+    ///
+    /// # Errors
+    /// Returning an error will throw an exception in JavaScript.
+    fn close(&self, _context: &mut Context) -> JsResult<()> {
+        Ok(())
+    }
+}
+
+/// The default implementation of a window host, which blocks every window that scripts
+/// try to open.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct DefaultWindowHost;
+
+impl WindowHost for DefaultWindowHost {
+    fn open(
+        &self,
+        _url: &JsString,
+        _target: &JsString,
+        _features: &JsString,
+        _context: &mut Context,
+    ) -> JsResult<Option<JsObject>> {
+        Ok(None)
+    }
+}
+
+/// The color scheme preferred by the user.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ColorScheme {
+    /// A light color scheme.
+    #[default]
+    Light,
+    /// A dark color scheme.
+    Dark,
+}
+
+/// The state of the viewport the window is displayed in, as provided by the embedder.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub struct ViewportState {
+    /// The width of the viewport, in CSS pixels.
+    pub width: f64,
+    /// The height of the viewport, in CSS pixels.
+    pub height: f64,
+    /// The ratio between the size of a device pixel and the size of a CSS pixel.
+    pub device_pixel_ratio: f64,
+    /// The horizontal scroll position of the viewport, in CSS pixels.
+    pub scroll_x: f64,
+    /// The vertical scroll position of the viewport, in CSS pixels.
+    pub scroll_y: f64,
+    /// The color scheme preferred by the user.
+    pub color_scheme: ColorScheme,
+    /// Whether the user prefers reduced motion.
+    pub reduced_motion: bool,
+}
+
+impl Default for ViewportState {
+    fn default() -> Self {
+        Self {
+            width: 1280.0,
+            height: 720.0,
+            device_pixel_ratio: 1.0,
+            scroll_x: 0.0,
+            scroll_y: 0.0,
+            color_scheme: ColorScheme::default(),
+            reduced_motion: false,
+        }
+    }
+}
+
+/// The internal state of the window module.
+#[derive(Trace, Finalize, JsData)]
+struct WindowState {
+    // SAFETY: Hosts are host objects that aren't reachable from JavaScript.
+    #[unsafe_ignore_trace]
+    host: Rc<dyn WindowHost>,
+    /// Whether the window was closed by a script.
+    closed: bool,
+    #[unsafe_ignore_trace]
+    viewport: ViewportState,
+    /// The `MediaQueryList` objects notified of viewport changes, i.e. the ones that had
+    /// `change` handlers the last time the viewport changed.
+    media_query_lists: Vec<JsObject>,
+}
+
+impl WindowState {
+    fn new(host: Rc<dyn WindowHost>) -> Self {
+        Self {
+            host,
+            closed: false,
+            viewport: ViewportState::default(),
+            media_query_lists: Vec::new(),
+        }
+    }
+
+    /// Notify `list` of the viewport changes, if it isn't notified yet.
+    fn track_media_query_list(&mut self, list: &JsClass<MediaQueryList>) {
+        let object = list.inner().upcast();
+        if self
+            .media_query_lists
+            .iter()
+            .any(|tracked| JsObject::equals(tracked, &object))
+        {
+            return;
+        }
+
+        // Changes that happened while the list wasn't notified are not reported.
+        let matches = list.borrow().evaluate(&self.viewport);
+        list.borrow_mut().last_matches = matches;
+        self.media_query_lists.push(object);
+    }
+
+    /// Get the window state from the context, or add it to the context with a
+    /// [`DefaultWindowHost`] if not present.
+    fn from_context(context: &mut Context) -> Gc<GcRefCell<Self>> {
+        if !context.has_data::<Gc<GcRefCell<Self>>>() {
+            context.insert_data(Gc::new(GcRefCell::new(Self::new(Rc::new(
+                DefaultWindowHost,
+            )))));
+        }
+
+        context
+            .get_data::<Gc<GcRefCell<Self>>>()
+            .expect("Should have inserted.")
+            .clone()
+    }
+}
+
+/// Returns `true` if the window was closed by a script.
+#[must_use]
+pub fn is_closed(context: &mut Context) -> bool {
+    WindowState::from_context(context).borrow().closed
+}
+
+/// Returns the current state of the viewport.
+#[must_use]
+pub fn viewport(context: &mut Context) -> ViewportState {
+    WindowState::from_context(context).borrow().viewport
+}
+
+/// Update the state of the viewport, and fire the events describing the changes.
+///
+/// A `resize` event is delivered to the global `onresize` handler if the size of the
+/// viewport changed, then a `scroll` event is delivered to the global `onscroll` handler
+/// if its scroll position changed. Finally, a `change` event is fired on every
+/// `MediaQueryList` whose queries started or stopped matching.
+///
+/// Exceptions thrown by the event handlers are reported to the global scope through
+/// [`report_exception`][crate::report::report_exception].
+///
+/// # Errors
+/// Returns an error if the exception thrown by a handler cannot be reported.
+pub fn set_viewport(viewport: ViewportState, context: &mut Context) -> JsResult<()> {
+    let (previous, lists) = {
+        let state = WindowState::from_context(context);
+        let mut state = state.borrow_mut();
+        let previous = std::mem::replace(&mut state.viewport, viewport);
+        // Release the lists whose handlers were all removed.
+        state.media_query_lists.retain(|object| {
+            object
+                .downcast_ref::<MediaQueryList>()
+                .is_some_and(|list| list.has_change_handlers())
+        });
+        (previous, state.media_query_lists.clone())
+    };
+
+    if (viewport.width, viewport.height) != (previous.width, previous.height) {
+        fire_global_event(js_string!("resize"), js_string!("onresize"), context)?;
+    }
+    if (viewport.scroll_x, viewport.scroll_y) != (previous.scroll_x, previous.scroll_y) {
+        fire_global_event(js_string!("scroll"), js_string!("onscroll"), context)?;
+    }
+
+    for object in lists {
+        let (event, handlers) = {
+            let Some(mut list) = object.downcast_mut::<MediaQueryList>() else {
+                continue;
+            };
+            let matches = list.evaluate(&viewport);
+            if matches == list.last_matches {
+                continue;
+            }
+            list.last_matches = matches;
+            (list.change_event(matches, context), list.change_handlers())
+        };
+
+        for handler in handlers {
+            if let Err(err) = handler.call(&object.clone().into(), &[event.clone().into()], context)
+            {
+                crate::report::report_exception(&err, context)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Call the global event handler named `handler` with an event of type `event_type`.
+fn fire_global_event(
+    event_type: JsString,
+    handler: JsString,
+    context: &mut Context,
+) -> JsResult<()> {
+    let global = context.global_object();
+    let handler = global.get(handler, context)?;
+    let Some(handler) = handler.as_callable() else {
+        return Ok(());
+    };
+
+    let event = ObjectInitializer::new(context)
+        .property(js_string!("type"), event_type, Attribute::all())
+        .build();
+    if let Err(err) = handler.call(&global.into(), &[event.into()], context) {
+        crate::report::report_exception(&err, context)?;
+    }
+    Ok(())
+}
+
+/// The getter of the global `closed` property.
+#[allow(clippy::unnecessary_wraps)]
+fn get_closed(_: &JsValue, _: &[JsValue], context: &mut Context) -> JsResult<JsValue> {
+    Ok(is_closed(context).into())
+}
+
+/// Create the getter of a global property reflecting a field of the viewport.
+fn viewport_getter(field: fn(&ViewportState) -> f64) -> NativeFunction {
+    NativeFunction::from_copy_closure(move |_, _, context| Ok(field(&viewport(context)).into()))
+}
+
+/// JavaScript module containing the `open`, `close` and `matchMedia` functions, and the
+/// `MediaQueryList` class.
+#[boa_module]
+pub mod js_module {
+    use boa_engine::class::Class;
+    use boa_engine::value::Convert;
+    use boa_engine::{Context, JsObject, JsResult, JsString, JsValue, js_string};
+
+    type MediaQueryList = super::MediaQueryList;
+
+    /// The [`open()`][mdn] method asks the host to load a resource into a new or
+    /// existing window, and returns the object representing that window, or `null`.
+    ///
+    /// # Errors
+    /// Returns an error if the arguments cannot be converted to strings, or if the host
+    /// fails to open the window.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/open
+    pub fn open(
+        url: Option<Convert<JsString>>,
+        target: Option<Convert<JsString>>,
+        features: Option<Convert<JsString>>,
+        context: &mut Context,
+    ) -> JsResult<JsValue> {
+        let url = url.map(|Convert(url)| url).unwrap_or_default();
+        let target = target
+            .map(|Convert(target)| target)
+            .filter(|target| !target.is_empty())
+            .unwrap_or_else(|| js_string!("_blank"));
+        let features = features
+            .map(|Convert(features)| features)
+            .unwrap_or_default();
+
+        let host = super::WindowState::from_context(context)
+            .borrow()
+            .host
+            .clone();
+        let window = host.open(&url, &target, &features, context)?;
+        Ok(window.map_or_else(JsValue::null, JsValue::from))
+    }
+
+    /// The [`close()`][mdn] method closes the current window. Closing a window that is
+    /// already closed does nothing.
+    ///
+    /// # Errors
+    /// Returns an error if the host fails to close the window.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/close
+    pub fn close(context: &mut Context) -> JsResult<()> {
+        let state = super::WindowState::from_context(context);
+        let host = {
+            let mut state = state.borrow_mut();
+            if state.closed {
+                return Ok(());
+            }
+            state.closed = true;
+            state.host.clone()
+        };
+
+        host.close(context)
+    }
+
+    /// The [`matchMedia()`][mdn] method returns a `MediaQueryList` object tracking whether
+    /// the media query list `query` matches the viewport.
+    ///
+    /// # Errors
+    /// Returns an error if `query` cannot be converted to a string.
+    ///
+    /// [mdn]: https://developer.mozilla.org/en-US/docs/Web/API/Window/matchMedia
+    pub fn match_media(
+        Convert(query): Convert<JsString>,
+        context: &mut Context,
+    ) -> JsResult<JsObject> {
+        let state = super::WindowState::from_context(context);
+        let viewport = state.borrow().viewport;
+        MediaQueryList::from_data(MediaQueryList::new(query, &viewport), context)
+    }
+}
+
+/// Register the `open()`, `close()` and `matchMedia()` functions, the `MediaQueryList`
+/// class, and the `closed` and viewport properties in the realm, delegating to `host`.
+/// Pass `None` as the realm to register globally.
+///
+/// # Errors
+/// If any of the functions or properties fail to register, an error is returned.
+pub fn register<H: WindowHost + 'static>(
+    host: H,
+    realm: Option<Realm>,
+    context: &mut Context,
+) -> JsResult<()> {
+    context.insert_data(Gc::new(GcRefCell::new(WindowState::new(Rc::new(host)))));
+    js_module::boa_register(realm.clone(), context)?;
+
+    // Accessors cannot be registered through `boa_module`.
+    let accessors = [
+        (
+            js_string!("closed"),
+            NativeFunction::from_fn_ptr(get_closed),
+        ),
+        (js_string!("innerWidth"), viewport_getter(|v| v.width)),
+        (js_string!("innerHeight"), viewport_getter(|v| v.height)),
+        (
+            js_string!("devicePixelRatio"),
+            viewport_getter(|v| v.device_pixel_ratio),
+        ),
+        (js_string!("scrollX"), viewport_getter(|v| v.scroll_x)),
+        (js_string!("scrollY"), viewport_getter(|v| v.scroll_y)),
+    ];
+
+    let previous_realm = realm.map(|realm| context.enter_realm(realm));
+    let result = accessors.into_iter().try_for_each(|(name, getter)| {
+        let getter = getter.to_js_function(context.realm());
+        context.global_object().define_property_or_throw(
+            name,
+            PropertyDescriptor::builder()
+                .get(getter)
+                .enumerable(true)
+                .configurable(true),
+            context,
+        )?;
+        Ok(())
+    });
+    if let Some(previous_realm) = previous_realm {
+        context.enter_realm(previous_realm);
+    }
+    result
+}
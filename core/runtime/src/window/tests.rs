@@ -0,0 +1,199 @@
+use super::{ColorScheme, DefaultWindowHost, ViewportState, WindowHost, WindowState};
+use crate::test::{TestAction, run_test_actions, run_test_actions_with};
+use boa_engine::object::ObjectInitializer;
+use boa_engine::property::Attribute;
+use boa_engine::{Context, JsObject, JsResult, JsString, js_string};
+use indoc::indoc;
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// A host recording the windows it is asked to open, and opening them as plain objects.
+#[derive(Debug, Default, Clone)]
+struct RecordingHost {
+    opened: Rc<RefCell<Vec<String>>>,
+    closes: Rc<RefCell<u32>>,
+}
+
+impl WindowHost for RecordingHost {
+    fn open(
+        &self,
+        url: &JsString,
+        target: &JsString,
+        features: &JsString,
+        context: &mut Context,
+    ) -> JsResult<Option<JsObject>> {
+        self.opened.borrow_mut().push(format!(
+            "{} {} {}",
+            url.to_std_string_escaped(),
+            target.to_std_string_escaped(),
+            features.to_std_string_escaped()
+        ));
+        let window = ObjectInitializer::new(context)
+            .property(js_string!("url"), url.clone(), Attribute::all())
+            .build();
+        Ok(Some(window))
+    }
+
+    fn close(&self, _context: &mut Context) -> JsResult<()> {
+        *self.closes.borrow_mut() += 1;
+        Ok(())
+    }
+}
+
+#[test]
+fn open_and_close() {
+    let context = &mut Context::default();
+    let host = RecordingHost::default();
+    super::register(host.clone(), None, context).unwrap();
+
+    run_test_actions_with(
+        [
+            TestAction::harness(),
+            TestAction::run(indoc! {r#"
+                const popup = open("https://example.com/", "popup", "width=100");
+                assertEq(popup.url, "https://example.com/");
+                open();
+
+                assert(!closed);
+                close();
+                assert(closed);
+                close();
+            "#}),
+        ],
+        context,
+    );
+
+    assert_eq!(
+        *host.opened.borrow(),
+        ["https://example.com/ popup width=100", " _blank "]
+    );
+    assert_eq!(*host.closes.borrow(), 1);
+    assert!(super::is_closed(context));
+}
+
+#[test]
+fn default_host_blocks_windows() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            super::register(DefaultWindowHost, None, context).unwrap();
+        }),
+        TestAction::run(r#"assertEq(open("https://example.com/"), null);"#),
+    ]);
+}
+
+#[test]
+fn match_media() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            super::register(DefaultWindowHost, None, context).unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            assert(matchMedia("").matches);
+            assert(matchMedia("screen and (min-width: 1000px)").matches);
+            assert(!matchMedia("print").matches);
+            assert(matchMedia("not print").matches);
+            assert(matchMedia("(orientation: landscape)").matches);
+            assert(!matchMedia("(max-width: 40em), (prefers-color-scheme: dark)").matches);
+            assert(!matchMedia("(unknown-feature)").matches);
+            assert(!matchMedia("screen and").matches);
+            assertEq(matchMedia("(min-width: 600px)").media, "(min-width: 600px)");
+
+            const events = [];
+            const dark = matchMedia("(prefers-color-scheme: dark)");
+            dark.onchange = (event) => events.push(`onchange ${event.matches}`);
+            dark.addEventListener("change", (event) => events.push(`listener ${event.media}`));
+
+            const narrow = matchMedia("(max-width: 600px)");
+            const legacy = () => events.push("legacy");
+            narrow.addListener(legacy);
+            narrow.removeListener(legacy);
+        "#}),
+        TestAction::inspect_context(|context| {
+            let viewport = ViewportState {
+                width: 400.0,
+                color_scheme: ColorScheme::Dark,
+                ..ViewportState::default()
+            };
+            super::set_viewport(viewport, context).unwrap();
+
+            // Setting the same viewport doesn't fire any event.
+            super::set_viewport(viewport, context).unwrap();
+            assert_eq!(super::viewport(context), viewport);
+        }),
+        TestAction::run(indoc! {r#"
+            assert(dark.matches);
+            assert(narrow.matches);
+            assertEq(events.join(), "onchange true,listener (prefers-color-scheme: dark)");
+        "#}),
+    ]);
+}
+
+#[test]
+fn match_media_keeps_only_lists_with_handlers() {
+    let tracked = |context: &mut Context| {
+        WindowState::from_context(context)
+            .borrow()
+            .media_query_lists
+            .len()
+    };
+
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            super::register(DefaultWindowHost, None, context).unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            matchMedia("(min-width: 600px)");
+            const listener = () => {};
+            const list = matchMedia("(max-width: 600px)");
+            list.addListener(listener);
+        "#}),
+        TestAction::inspect_context(move |context| assert_eq!(tracked(context), 1)),
+        TestAction::run("list.removeListener(listener);"),
+        TestAction::inspect_context(move |context| {
+            super::set_viewport(ViewportState::default(), context).unwrap();
+            assert_eq!(tracked(context), 0);
+        }),
+    ]);
+}
+
+#[test]
+fn viewport_properties_and_events() {
+    run_test_actions([
+        TestAction::harness(),
+        TestAction::inspect_context(|context| {
+            super::register(DefaultWindowHost, None, context).unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            assertEq(innerWidth, 1280);
+            assertEq(innerHeight, 720);
+            assertEq(devicePixelRatio, 1);
+            assertEq(scrollX, 0);
+            assertEq(scrollY, 0);
+
+            const events = [];
+            onresize = (event) => events.push(`${event.type} ${innerWidth}x${innerHeight}`);
+            onscroll = (event) => events.push(`${event.type} ${scrollX},${scrollY}`);
+        "#}),
+        TestAction::inspect_context(|context| {
+            let viewport = ViewportState {
+                width: 800.0,
+                device_pixel_ratio: 2.0,
+                ..ViewportState::default()
+            };
+            super::set_viewport(viewport, context).unwrap();
+
+            let viewport = ViewportState {
+                scroll_y: 150.0,
+                ..viewport
+            };
+            super::set_viewport(viewport, context).unwrap();
+        }),
+        TestAction::run(indoc! {r#"
+            assertEq(devicePixelRatio, 2);
+            assertEq(events.join(), "resize 800x720,scroll 0,150");
+        "#}),
+    ]);
+}
@@ -5,17 +5,23 @@ use boa_runtime::RuntimeExtension;
 use rstest::rstest;
 use std::path::PathBuf;
 
-#[rstest]
-fn clone(#[files("tests/clone/**/*.js")] path: PathBuf) {
-    let context = &mut Context::default();
-    boa_runtime::clone::register(None, context).expect("Could not register runtime");
+/// Create a context with `structuredClone`, the console and the test harness.
+fn context() -> Context {
+    let mut context = Context::default();
+    boa_runtime::clone::register(None, &mut context).expect("Could not register runtime");
     boa_runtime::extensions::ConsoleExtension::default()
-        .register(None, context)
+        .register(None, &mut context)
         .expect("Could not register console");
 
     let harness_path = PathBuf::from("./assets/harness.js");
     let harness = Source::from_filepath(&harness_path).expect("Could not load harness");
     context.eval(harness).expect("Could not eval source");
+    context
+}
+
+#[rstest]
+fn clone(#[files("tests/clone/**/*.js")] path: PathBuf) {
+    let context = &mut context();
 
     let source = Source::from_filepath(&path).expect("Could not load source");
 
@@ -27,3 +33,32 @@ fn clone(#[files("tests/clone/**/*.js")] path: PathBuf) {
         panic!("Execution error: {e}");
     }
 }
+
+#[cfg(feature = "temporal")]
+#[test]
+fn clone_temporal() {
+    let context = &mut context();
+
+    let source = Source::from_bytes(
+        r#"
+            for (const value of [
+                Temporal.PlainDate.from("2020-01-01"),
+                Temporal.Duration.from({ hours: 1 }),
+                Temporal.Instant.fromEpochMilliseconds(0),
+            ]) {
+                let error;
+                try {
+                    structuredClone(value);
+                } catch (e) {
+                    error = e;
+                }
+                assert(error instanceof Error, `cloning ${value} should throw`);
+                assert(error.message.startsWith("DataCloneError"));
+            }
+        "#,
+    );
+
+    if let Err(e) = context.eval(source) {
+        panic!("Evaluation failed: {e}");
+    }
+}
@@ -0,0 +1,20 @@
+const wrapper = Object(2n ** 70n);
+const original = {
+  value: -(2n ** 64n),
+  wrapper,
+  again: wrapper,
+  signed: new BigInt64Array([-1n, 2n ** 62n]),
+  unsigned: new BigUint64Array([2n ** 64n - 1n]),
+};
+
+const clone = structuredClone(original);
+
+assertEq(clone.value, -(2n ** 64n));
+assertEq(typeof clone.wrapper, "object");
+assert(clone.wrapper !== wrapper);
+assertEq(clone.wrapper.valueOf(), 2n ** 70n);
+assertEq(clone.wrapper, clone.again);
+assert(clone.signed instanceof BigInt64Array);
+assertArrayEqual(clone.signed, [-1n, 2n ** 62n]);
+assert(clone.unsigned instanceof BigUint64Array);
+assertArrayEqual(clone.unsigned, [2n ** 64n - 1n]);
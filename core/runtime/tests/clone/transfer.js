@@ -35,3 +35,27 @@
   assertEq(object1.array.byteLength, 0);
   assertArrayEqual(object2.array, [1, 2, 3, 4]);
 }
+
+{
+  // Buffers are only detached once the whole value was cloned.
+  const buffer = new ArrayBuffer(8);
+  assertThrows(() => structuredClone({ buffer, f() {} }, { transfer: [buffer] }));
+  assertEq(buffer.byteLength, 8);
+
+  // The same buffer referenced twice is transferred once.
+  const clone = structuredClone([buffer, buffer], { transfer: [buffer] });
+  assert(clone[0] === clone[1]);
+  assertEq(clone[0].byteLength, 8);
+  assertEq(buffer.byteLength, 0);
+}
+
+{
+  // Invalid transfer lists throw a DataCloneError without detaching anything.
+  const buffer = new ArrayBuffer(8);
+  assertThrows(() => structuredClone(buffer, { transfer: [buffer, buffer] }));
+  assertThrows(() => structuredClone(buffer, { transfer: [buffer, {}] }));
+  assertEq(buffer.byteLength, 8);
+
+  structuredClone(buffer, { transfer: [buffer] });
+  assertThrows(() => structuredClone(null, { transfer: [buffer] }));
+}
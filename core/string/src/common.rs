@@ -376,6 +376,11 @@ const RAW_STATICS: &[JsStr<'static>] = &[
     JsStr::latin1("Memory".as_bytes()),
     JsStr::latin1("Table".as_bytes()),
     JsStr::latin1("Global".as_bytes()),
+    // WebAssembly import/export descriptors
+    JsStr::latin1("module".as_bytes()),
+    JsStr::latin1("kind".as_bytes()),
+    JsStr::latin1("memory".as_bytes()),
+    JsStr::latin1("table".as_bytes()),
     JsStr::latin1("Worker".as_bytes()),
     JsStr::latin1("WorkerNavigator".as_bytes()),
     JsStr::latin1("SharedWorker".as_bytes()),
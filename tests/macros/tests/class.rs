@@ -111,6 +111,19 @@ fn boa_class() {
             assertEq(Animal.prototype.method.length, 11, "Method.length");
             assertEq(Animal.prototype.speak.length, 0, "speak.length");
             assertEq(Animal.prototype.setAge.length, 1, "setAge.length");
+
+            assertEq(Object.prototype.toString.call(pet), "[object Animal]", "@@toStringTag");
+            try {
+                Object.getOwnPropertyDescriptor(Animal.prototype, "age").get.call({});
+                throw "AssertionError: brand check did not throw";
+            } catch (e) {
+                assertEq(e instanceof TypeError, true, "Brand check error type");
+                assertEq(
+                    e.message,
+                    "'age' called on an object that does not implement interface Animal.",
+                    "Brand check message"
+                );
+            }
      "#,
         ))
         .expect("Could not evaluate script");